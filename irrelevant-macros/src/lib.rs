@@ -0,0 +1,252 @@
+//! Attribute macros for the `irrelevant` crate. They are meant to be used through its `attributes` feature, which
+//! re-exports them.
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// Ignores the parameters of a function marked with `#[irrelevant]`, like `irrelevant!` at the top of its body would.
+///
+/// Each marked parameter is ignored as the first statement of the body, and shadowed, so it can't be used by accident.
+/// The attribute takes the reason for ignoring the parameter, either as `#[irrelevant("reason")]` or as
+/// `#[irrelevant(reason = "reason")]`, and optionally an assumption about its value, as `assume = condition`. The
+/// assumption can be anything `irrelevant!` accepts after the reason, including named assumptions.
+///
+/// The generated code refers to the `irrelevant` crate as `::irrelevant`. If it is renamed, or only reachable through
+/// a re-export, its path can be given as `#[irrelevant_params(crate = path)]`. Only parameters bound to a name can be
+/// ignored, so `self` and destructuring patterns are not supported.
+#[proc_macro_attribute]
+pub fn irrelevant_params(attr: TokenStream, item: TokenStream) -> TokenStream {
+    match crate_path(attr).and_then(|krate| expand(item, &krate)) {
+        Ok(item) => item,
+        Err((span, message)) => error(span, message),
+    }
+}
+/// An error at a place in the source code.
+type Error = (Span, &'static str);
+/// Parses the arguments of `irrelevant_params`, returning the path to the `irrelevant` crate.
+fn crate_path(attr: TokenStream) -> Result<Vec<TokenTree>, Error> {
+    const USAGE: &str = "expected no arguments, or `crate = path` with the path to the `irrelevant` crate";
+    let args: Vec<TokenTree> = attr.into_iter().collect();
+    match args.as_slice() {
+        [] => Ok(vec![
+            TokenTree::Punct(joint(':', Span::call_site())),
+            TokenTree::Punct(alone(':', Span::call_site())),
+            TokenTree::Ident(Ident::new("irrelevant", Span::call_site())),
+        ]),
+        [name, eq, path @ ..] if is_ident(name, "crate") && is_punct(eq, '=') && !path.is_empty() => Ok(path.to_vec()),
+        [first, ..] => Err((first.span(), USAGE)),
+    }
+}
+/// Removes the `#[irrelevant]` attributes from the parameters of a function, and ignores them at the top of its body.
+fn expand(item: TokenStream, krate: &[TokenTree]) -> Result<TokenStream, Error> {
+    let mut tokens: Vec<TokenTree> = item.into_iter().collect();
+    let fn_pos = tokens
+        .iter()
+        .position(|token| is_ident(token, "fn"))
+        .ok_or((Span::call_site(), "`irrelevant_params` can only be used on functions"))?;
+    // The parameters are the first parenthesized group after the name, outside of the generic parameters.
+    let mut depth = 0usize;
+    let params_pos = (fn_pos + 1..tokens.len())
+        .find(|&pos| match &tokens[pos] {
+            TokenTree::Punct(punct) if punct.as_char() == '<' => {
+                depth += 1;
+                false
+            }
+            TokenTree::Punct(punct) if punct.as_char() == '>' && !is_arrow(&tokens, pos) => {
+                depth = depth.saturating_sub(1);
+                false
+            }
+            TokenTree::Group(group) => depth == 0 && group.delimiter() == Delimiter::Parenthesis,
+            _ => false,
+        })
+        .ok_or((tokens[fn_pos].span(), "expected the parameters of the function"))?;
+    let Some(TokenTree::Group(body)) = tokens.last() else {
+        return Err((tokens[fn_pos].span(), "expected the body of the function"));
+    };
+    if body.delimiter() != Delimiter::Brace {
+        return Err((body.span(), "expected the body of the function"));
+    }
+    let TokenTree::Group(params) = &tokens[params_pos] else {
+        unreachable!("the parameters are a group");
+    };
+    let mut stripped = Vec::new();
+    let mut ignores = Vec::new();
+    for param in split_params(params.stream()) {
+        let (param, ignore) = strip_attribute(param)?;
+        if let Some(ignore) = ignore {
+            ignores.extend(ignore_param(&param, ignore, krate)?);
+        }
+        if !stripped.is_empty() {
+            stripped.push(TokenTree::Punct(Punct::new(',', Spacing::Alone)));
+        }
+        stripped.extend(param);
+    }
+    let mut new_params = Group::new(Delimiter::Parenthesis, stripped.into_iter().collect());
+    new_params.set_span(params.span());
+    let mut new_body = Group::new(Delimiter::Brace, ignores.into_iter().chain(body.stream()).collect());
+    new_body.set_span(body.span());
+    tokens[params_pos] = TokenTree::Group(new_params);
+    *tokens.last_mut().expect("the body was found") = TokenTree::Group(new_body);
+    Ok(tokens.into_iter().collect())
+}
+/// Splits the parameters of a function at the commas between them, skipping the commas in generic arguments.
+fn split_params(params: TokenStream) -> Vec<Vec<TokenTree>> {
+    let tokens: Vec<TokenTree> = params.into_iter().collect();
+    let mut split = vec![Vec::new()];
+    let mut depth = 0usize;
+    for (pos, token) in tokens.iter().enumerate() {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == ',' && depth == 0 => {
+                split.push(Vec::new());
+                continue;
+            }
+            TokenTree::Punct(punct) if punct.as_char() == '<' => depth += 1,
+            TokenTree::Punct(punct) if punct.as_char() == '>' && !is_arrow(&tokens, pos) => {
+                depth = depth.saturating_sub(1);
+            }
+            _ => {}
+        }
+        split.last_mut().expect("there is always a parameter").push(token.clone());
+    }
+    split.retain(|param| !param.is_empty());
+    split
+}
+/// The arguments of an `#[irrelevant]` attribute.
+struct Ignore {
+    span: Span,
+    reason: Option<TokenTree>,
+    assumption: Vec<TokenTree>,
+}
+/// Removes the `#[irrelevant]` attribute from a parameter, returning its arguments if it was there.
+fn strip_attribute(param: Vec<TokenTree>) -> Result<(Vec<TokenTree>, Option<Ignore>), Error> {
+    let mut stripped = Vec::new();
+    let mut ignore = None;
+    let mut tokens = param.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        let is_attribute = matches!(&token, TokenTree::Punct(punct) if punct.as_char() == '#')
+            && matches!(tokens.peek(), Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Bracket);
+        if !is_attribute {
+            stripped.push(token);
+            continue;
+        }
+        let Some(TokenTree::Group(attribute)) = tokens.next() else {
+            unreachable!("the attribute was peeked");
+        };
+        let mut inner = attribute.stream().into_iter();
+        match inner.next() {
+            Some(name) if is_ident(&name, "irrelevant") => {
+                if ignore.is_some() {
+                    return Err((attribute.span(), "a parameter can only be ignored once"));
+                }
+                ignore = Some(parse_ignore(attribute.span(), inner.collect())?);
+            }
+            _ => {
+                stripped.push(token);
+                stripped.push(TokenTree::Group(attribute));
+            }
+        }
+    }
+    Ok((stripped, ignore))
+}
+/// Parses the arguments of an `#[irrelevant]` attribute, following its name.
+fn parse_ignore(span: Span, args: Vec<TokenTree>) -> Result<Ignore, Error> {
+    const USAGE: &str = "expected `#[irrelevant(\"reason\")]`, optionally followed by `, assume = condition`";
+    let args = match args.as_slice() {
+        [] => return Ok(Ignore { span, reason: None, assumption: Vec::new() }),
+        [TokenTree::Group(group)] if group.delimiter() == Delimiter::Parenthesis => group.stream(),
+        _ => return Err((span, USAGE)),
+    };
+    let args: Vec<TokenTree> = args.into_iter().collect();
+    let (reason, rest) = match args.as_slice() {
+        [reason @ TokenTree::Literal(_), rest @ ..] => (reason, rest),
+        [name, TokenTree::Punct(eq), reason @ TokenTree::Literal(_), rest @ ..]
+            if is_ident(name, "reason") && eq.as_char() == '=' =>
+        {
+            (reason, rest)
+        }
+        _ => return Err((span, USAGE)),
+    };
+    let assumption = match rest {
+        [] => Vec::new(),
+        [comma] if is_punct(comma, ',') => Vec::new(),
+        [comma, name, eq, assumption @ ..]
+            if is_punct(comma, ',') && is_ident(name, "assume") && is_punct(eq, '=') && !assumption.is_empty() =>
+        {
+            assumption.to_vec()
+        }
+        _ => return Err((span, USAGE)),
+    };
+    Ok(Ignore { span, reason: Some(reason.clone()), assumption })
+}
+/// Generates the `irrelevant!` invocation ignoring a parameter, with `krate` as the path to the `irrelevant` crate.
+fn ignore_param(param: &[TokenTree], ignore: Ignore, krate: &[TokenTree]) -> Result<TokenStream, Error> {
+    const UNNAMED: &str = "only parameters bound to a name can be ignored";
+    let name = match param {
+        [TokenTree::Ident(name), colon, ..] if is_punct(colon, ':') => name,
+        [TokenTree::Ident(mutable), TokenTree::Ident(name), colon, ..]
+            if mutable.to_string() == "mut" && is_punct(colon, ':') =>
+        {
+            name
+        }
+        _ => return Err((ignore.span, UNNAMED)),
+    };
+    if name.to_string() == "self" {
+        return Err((ignore.span, UNNAMED));
+    }
+    let mut args = vec![TokenTree::Ident(name.clone())];
+    if let Some(reason) = ignore.reason {
+        args.push(TokenTree::Punct(Punct::new(',', Spacing::Alone)));
+        args.push(reason);
+    }
+    if !ignore.assumption.is_empty() {
+        args.push(TokenTree::Punct(Punct::new(',', Spacing::Alone)));
+        args.extend(ignore.assumption);
+    }
+    let span = ignore.span;
+    Ok(krate
+        .iter()
+        .cloned()
+        .chain([
+            TokenTree::Punct(joint(':', span)),
+            TokenTree::Punct(alone(':', span)),
+            TokenTree::Ident(Ident::new("irrelevant", span)),
+            TokenTree::Punct(alone('!', span)),
+            TokenTree::Group(Group::new(Delimiter::Parenthesis, args.into_iter().collect())),
+            TokenTree::Punct(alone(';', span)),
+        ])
+        .collect())
+}
+/// Checks if the `>` at `pos` is a part of `->`, rather than closing generic arguments.
+fn is_arrow(tokens: &[TokenTree], pos: usize) -> bool {
+    pos > 0
+        && matches!(&tokens[pos - 1], TokenTree::Punct(punct) if punct.as_char() == '-' && punct.spacing() == Spacing::Joint)
+}
+fn is_ident(token: &TokenTree, name: &str) -> bool {
+    matches!(token, TokenTree::Ident(ident) if ident.to_string() == name)
+}
+fn is_punct(token: &TokenTree, ch: char) -> bool {
+    matches!(token, TokenTree::Punct(punct) if punct.as_char() == ch)
+}
+fn joint(ch: char, span: Span) -> Punct {
+    let mut punct = Punct::new(ch, Spacing::Joint);
+    punct.set_span(span);
+    punct
+}
+fn alone(ch: char, span: Span) -> Punct {
+    let mut punct = Punct::new(ch, Spacing::Alone);
+    punct.set_span(span);
+    punct
+}
+/// Expands to a `compile_error!` with `message`, pointing at `span`.
+fn error(span: Span, message: &str) -> TokenStream {
+    let mut message = Literal::string(message);
+    message.set_span(span);
+    let mut args = Group::new(Delimiter::Parenthesis, TokenTree::Literal(message).into());
+    args.set_span(span);
+    [
+        TokenTree::Ident(Ident::new("compile_error", span)),
+        TokenTree::Punct(alone('!', span)),
+        TokenTree::Group(args),
+        TokenTree::Punct(alone(';', span)),
+    ]
+    .into_iter()
+    .collect()
+}
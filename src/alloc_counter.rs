@@ -0,0 +1,90 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+std::thread_local! {
+    /// The number of allocations made by the current thread, through a [`CountingAllocator`].
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+}
+/// Counts an allocation made by the current thread.
+fn count_allocation() {
+    // The count is unavailable while the thread is being torn down, so those allocations are not counted.
+    let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+}
+/// Returns the number of allocations made by the current thread so far.
+fn allocations() -> u64 {
+    ALLOCATIONS.try_with(Cell::get).unwrap_or(0)
+}
+/// A global allocator, which counts the allocations made through it, for the `no_alloc_since` assumption. Requires
+/// the `alloc-counter` feature.
+///
+/// It wraps another allocator ([`System`] by default), which does the actual allocating. Allocations and reallocations
+/// are counted, deallocations are not. Every thread counts its own allocations, so allocations made by other threads
+/// never cause violations.
+/// ```
+/// # use irrelevant::*;
+/// #[global_allocator]
+/// static ALLOCATOR: CountingAllocator = CountingAllocator::new();
+/// # fn main() {}
+/// ```
+#[derive(Debug, Default)]
+pub struct CountingAllocator<A = System> {
+    allocator: A,
+}
+impl CountingAllocator {
+    /// Creates an allocator, counting the allocations made with [`System`].
+    pub const fn new() -> Self {
+        Self { allocator: System }
+    }
+}
+impl<A> CountingAllocator<A> {
+    /// Creates an allocator, counting the allocations made with `allocator`.
+    pub const fn wrap(allocator: A) -> Self {
+        Self { allocator }
+    }
+}
+// SAFETY: All the calls are forwarded to the wrapped allocator, which upholds the contract of `GlobalAlloc`.
+unsafe impl<A: GlobalAlloc> GlobalAlloc for CountingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count_allocation();
+        self.allocator.alloc(layout)
+    }
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count_allocation();
+        self.allocator.alloc_zeroed(layout)
+    }
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count_allocation();
+        self.allocator.realloc(ptr, layout, new_size)
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.allocator.dealloc(ptr, layout);
+    }
+}
+/// The number of allocations a thread made at some point, to check no allocations were made after it, with the
+/// `no_alloc_since(marker)` assumption.
+///
+/// Allocations are only counted by a [`CountingAllocator`], so it has to be the global allocator.
+/// ```
+/// # use irrelevant::*;
+/// # #[global_allocator]
+/// # static ALLOCATOR: CountingAllocator = CountingAllocator::new();
+/// # fn main() {
+/// let marker = AllocMarker::now();
+/// let sum: u32 = [1, 2, 3].iter().sum();
+/// irrelevant!(sum, "The sum is only computed to warm up the cache.", no_alloc_since(marker));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocMarker {
+    allocations: u64,
+}
+impl AllocMarker {
+    /// Marks the current number of allocations made by the current thread.
+    pub fn now() -> Self {
+        Self { allocations: allocations() }
+    }
+    /// Returns the number of allocations the current thread made since the marker.
+    pub fn allocations_since(&self) -> u64 {
+        allocations().saturating_sub(self.allocations)
+    }
+}
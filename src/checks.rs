@@ -0,0 +1,2532 @@
+//! Helpers backing the named assumptions of [`irrelevant`](crate::irrelevant).
+//!
+//! Named assumptions are written in place of a condition, and work with all the variants of the macro:
+//! * `last_sender` - the value is the last sender of its channel. See [`SenderCount`].
+//! * `unique` - the value is the only strong reference to its data, like an `Arc` or `Rc`. See [`RefCounted`].
+//! * `drained` - the value is a collection with no entries left. See [`Collection`].
+//! * `contains(&item)` - the value is a collection containing `item`. Uses its `contains` method.
+//! * `uncontended` - the value is a lock, which can be acquired without waiting. See [`Lock`].
+//! * `idle` - the value is a thread pool with no tasks running. See [`register_pool`].
+//! * `empty` - the value is a concurrent queue or stack with no items left. See [`ConcurrentQueue`].
+//! * `all_unique` - the value is a collection without duplicate elements. See [`all_unique`].
+//! * `no_cycle` - the value is the root of a graph (like a tree of `Rc`s), without any cycles reachable from it.
+//!   See [`Traverse`].
+//! * `count == n` - the value is an iterator yielding exactly `n` items. **This consumes the iterator.** See [`count`].
+//! * `ok == expected` - the value is a `Result`, which is `Ok` with a value equal to `expected`. See [`ok_eq`].
+//! * `none_when(cond)` - the value is an `Option`, which is `None` whenever `cond` holds, like an id which is only
+//!   assigned once a record is saved. See [`none_when`].
+//! * `min_key == key` and `max_key == key` - the value is an ordered map (like a `BTreeMap`), with `key` as its smallest
+//!   or largest key. Uses its `first_key_value` and `last_key_value` methods, and compares keys with [`eq`].
+//! * `plausible(min, max)` - the value is a reading between `min` and `max`, inclusive, and not `NaN`.
+//!   See [`plausible`].
+//! * `close_to(expected)` and `close_to(expected, rtol = r, atol = a)` - the value is a float close to `expected`,
+//!   within a relative and an absolute tolerance, like `numpy.isclose`. The tolerances default to `rtol = 1e-5` and
+//!   `atol = 1e-8`. See [`close_to`].
+//! * `within_ulps(expected, ulps)` - the value is a float at most `ulps` units in the last place away from `expected`.
+//! * `elementwise_close(expected, tol)` - the value is a slice of floats with the same length as `expected`, and each
+//!   of them at most `tol` away from the expected one. See [`elementwise_close`].
+//! * `within_p99` - the value is a number, at most the 99th percentile of the values previously ignored at the same
+//!   place. Requires the `std` feature. See [`within_p99`].
+//! * `deterministic` - the value is equal to the first value of its type ignored at the same place, like the result of
+//!   a computation which is supposed to be pure. Requires the `std` feature. See [`deterministic`].
+//! * `after_last_global` - the value is a `u64` timestamp (like nanoseconds since the Unix epoch), not before the
+//!   latest timestamp ignored with `after_last_global` anywhere in the program, by any thread. This catches clocks going
+//!   backwards. See [`after_last_global`].
+//!   See [`within_ulps`].
+//! * `loopback` - the value is a socket address (like a `SocketAddr`) on the loopback interface. See [`SocketAddress`].
+//! * `no_overflow_add(rhs)`, `no_overflow_sub(rhs)` and `no_overflow_mul(rhs)` - the value is an integer, which
+//!   doesn't overflow when `rhs` is added to it, subtracted from it, or multiplied with it. Uses its `checked_add`,
+//!   `checked_sub` and `checked_mul` methods.
+//! * `popcount == n` - the value is a bitset with exactly `n` bits set. See [`Popcount`].
+//! * `all_ones` and `all_zeros` - the value is an unsigned integer (like a hardware register), with all its bits set or
+//!   cleared. Violations show the actual bits in hexadecimal. See [`all_ones`] and [`all_zeros`].
+//! * `heap_bytes <= max` - the value is a collection using at most about `max` bytes of heap memory. See [`HeapBytes`].
+//! * `in_grid(width, height)` - the values are `[x, y]` coordinates within a grid of `width` by `height` cells,
+//!   ignored together, like `irrelevant!([x, y], "reason", in_grid(width, height))`.
+//! * `valid_range` - the value is a range (like a `Range`), which doesn't start after its end. Uses its `RangeBounds`
+//!   implementation.
+//! * `no_alloc_since(marker)` - the current thread made no allocations since the [`AllocMarker`](crate::AllocMarker)
+//!   `marker`. Requires the `alloc-counter` feature. See [`no_alloc_since`].
+//! * `in range` - the value is in `range`, like `in 0..len`. Any kind of range can be used. See [`within`].
+//! * `in_range(start, end)` - the value is a pointer into the memory range from `start` up to `end`. See [`in_range`].
+//! * `a_tty` and `not_a_tty` - the value is a stream (like `Stdout`), which is or isn't connected to a terminal.
+//!   See [`Terminal`].
+//! * `absolute` - the value is an absolute path. See [`absolute`].
+//! * `under(root)` - the value is a path within the directory `root`, without escaping it with `..` components or
+//!   symbolic links. Relative paths are relative to `root`. See [`under`].
+//! * `utc` and `tz == name` - the process runs in UTC, or in the timezone `name`. See [`utc`] and [`tz_is`].
+//! * `after(min_secs)` - the value is a time (like a `SystemTime`), at least `min_secs` seconds after the Unix epoch.
+//!   See [`after`].
+//! * `env_is(name)` and `env_is(name, var = "VAR")` - the process runs in the environment `name`, like `production`,
+//!   as set by the `APP_ENV` environment variable, or by `VAR`. See [`env_is`].
+//! * `witness(Token)` - the value is [`Witnessed`] by a `Token`, proving it was created by a sanctioned constructor.
+//! * `starts_with_any [a, b]` - the value is a string starting with one of the prefixes. See [`starts_with_any`].
+//! * `inline_capable(max)` - the value is a string at most `max` bytes long, so a small-string-optimized type can
+//!   store it without allocating.
+//! * `trimmed` - the value is a string without leading or trailing whitespace.
+//! * `valid_variant: Enum` - the value is an integer, which is a discriminant of `Enum`. See [`Discriminant`].
+//! * `be_u32 == expected` and `le_u32 == expected` - the value is a byte buffer starting with `expected`, in big-endian
+//!   or little-endian byte order. See [`be_u32`] and [`le_u32`].
+//! * `no_byte(byte)` - the value is a byte buffer, which doesn't contain `byte`, like a string passed on as a C string
+//!   without null bytes. See [`no_byte`].
+//! * `valid_utf16` - the value is a slice of UTF-16 code units (like a wide string from a Windows API), without
+//!   unpaired surrogates. See [`valid_utf16`].
+//! * `validator(name)` - the value passes the validator registered as `name`. See [`register_validator`].
+//! * `matches_baseline(key)` - the value is equal to the baseline registered as `key`, like a configuration value
+//!   captured at startup. See [`register_baseline`](crate::register_baseline).
+//! * `cached_valid(validator)` - the value is a string, accepted by `validator`, a function taking a `&str`. With the
+//!   `validation-cache` feature, the results of each validator are cached for recently ignored strings. See
+//!   [`cached_valid`].
+//! * `dangling` - the value is a dangling pointer, like the one from `NonNull::dangling`. See [`Pointer`].
+//! * `atomic_aligned::<T>()` - the value is a pointer, aligned for atomic access to a `T`. See [`atomic_aligned`].
+//! * `same_layout_as Type` - the value has the same size and alignment as `Type`. This is checked at compile time.
+//!   See [`same_layout_as`].
+//! * `static_closure` - the value is a closure (or any other value) without borrowed captures, so it is `'static`.
+//!   This is checked at compile time. See [`Assumptions::static_closure`].
+//! * `cfg_consistent(predicate)` - the crate is built with cfg flags satisfying `predicate`, like
+//!   `cfg_consistent(all(feature = "a", not(feature = "b")))`. The flags are those of the crate ignoring the value.
+//! * `snapshot "path"` - the `Debug` representation of the value matches the snapshot stored in the file at `path`.
+//!   Requires the `snapshot` feature. See [`snapshot`].
+//! * `crc32 == checksum` - the value is a byte buffer with the CRC-32 `checksum`. See [`crc32`].
+//! * `is_uuid` - the value is a string containing a UUID. See [`is_uuid`].
+//! * `is_hex` - the value is a string of hexadecimal digits. See [`is_hex`].
+//! * `invariant` - the invariant of the value, defined once for its type, holds. See [`Invariant`].
+//! * `is_default` - the value is equal to the default value of its type, like the initial state of a state machine.
+//!   Violations name the actual variant, from the start of its `Debug` representation. See [`is_default`].
+//! * `type_name == "name"` - the value is of the type called `name` by [`type_name`](core::any::type_name), like
+//!   `"alloc::string::String"`. Violations name the actual type. Type names are not guaranteed to be stable between
+//!   compiler versions, so this is best suited to documenting the expected instantiation of generic code.
+//!
+//! Named assumptions without arguments are methods of [`Assumptions`]. Like any other condition written as just a name,
+//! `irrelevant!(val, "reason", name)` checks `val.name()`, so a method of the value with the same name takes precedence.
+#[cfg(feature = "std")]
+use crate::Location;
+#[cfg(feature = "std")]
+use core::any::{Any, TypeId};
+use core::convert::Infallible;
+use core::fmt;
+use core::hash::Hash;
+use core::ops::{Bound, RangeBounds};
+#[cfg(feature = "std")]
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+#[cfg(feature = "std")]
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+#[cfg(feature = "std")]
+use std::rc::Rc;
+#[cfg(feature = "std")]
+use std::sync::{mpsc, Arc, Mutex, PoisonError, RwLock, TryLockError};
+
+/// The outcome of a check: either a condition, or a `Result` describing why an assumption doesn't hold.
+pub trait Outcome {
+    /// Describes why an assumption doesn't hold.
+    type Details: fmt::Display;
+    /// Returns `Ok` if the assumption holds, or the details of its violation, if there are any.
+    fn into_result(self) -> Result<(), Option<Self::Details>>;
+}
+impl Outcome for bool {
+    type Details = Infallible;
+    fn into_result(self) -> Result<(), Option<Infallible>> {
+        if self {
+            Ok(())
+        } else {
+            Err(None)
+        }
+    }
+}
+impl<D: fmt::Display> Outcome for Result<(), D> {
+    type Details = D;
+    fn into_result(self) -> Result<(), Option<D>> {
+        self.map_err(Some)
+    }
+}
+
+/// The named assumptions, which can be checked about any value they make sense for.
+///
+/// The methods are only ever called by the shorthand of [`irrelevant`](crate::irrelevant), which brings this trait into
+/// scope. Inherent methods of the value take precedence over them, while a method of another trait in scope with the same
+/// name makes the call ambiguous: write the full condition, like `val.name()`, in that case.
+pub trait Assumptions {
+    /// The value is the last sender of its channel.
+    fn last_sender(&self) -> Result<(), LiveSenders>
+    where
+        Self: SenderCount,
+    {
+        let count = self.sender_count();
+        if count == 1 {
+            Ok(())
+        } else {
+            Err(LiveSenders { count })
+        }
+    }
+    /// The value is the only strong reference to its data.
+    fn unique(&self) -> Result<(), StrongReferences>
+    where
+        Self: RefCounted,
+    {
+        match self.strong_count() {
+            1 => Ok(()),
+            count => Err(StrongReferences { count }),
+        }
+    }
+    /// The value is a collection with no entries left.
+    fn drained(&self) -> Result<(), Remaining>
+    where
+        Self: Collection,
+    {
+        match self.entries() {
+            0 => Ok(()),
+            entries => Err(Remaining { entries }),
+        }
+    }
+    /// The value is a lock, which can be acquired without waiting.
+    fn uncontended(&self) -> Result<(), Contended>
+    where
+        Self: Lock,
+    {
+        if self.try_acquire() {
+            Ok(())
+        } else {
+            Err(Contended)
+        }
+    }
+    /// The value is a thread pool with no tasks running.
+    #[cfg(feature = "std")]
+    fn idle(&self) -> Result<(), Busy>
+    where
+        Self: Any + Sized,
+    {
+        idle(self)
+    }
+    /// The value is a concurrent queue or stack with no items left.
+    fn empty(&self) -> Result<(), NotEmpty>
+    where
+        Self: ConcurrentQueue,
+    {
+        empty(self)
+    }
+    /// The value is a collection without duplicate elements.
+    #[cfg(feature = "std")]
+    fn all_unique<'a>(&'a self) -> Result<(), Duplicate<<&'a Self as IntoIterator>::Item>>
+    where
+        &'a Self: IntoIterator,
+        <&'a Self as IntoIterator>::Item: Hash + Eq,
+    {
+        all_unique(self)
+    }
+    /// The value is the root of a graph without cycles.
+    #[cfg(feature = "std")]
+    fn no_cycle(&self) -> Result<(), Cycle<<Self as Traverse>::Id>>
+    where
+        Self: Traverse,
+    {
+        no_cycle(self)
+    }
+    /// The value is a socket address on the loopback interface.
+    #[cfg(feature = "std")]
+    fn loopback(&self) -> Result<(), NotLoopback<'_, Self>>
+    where
+        Self: SocketAddress,
+    {
+        if self.ip().is_loopback() {
+            Ok(())
+        } else {
+            Err(NotLoopback { address: self })
+        }
+    }
+    /// The value is an unsigned integer, with all its bits set.
+    fn all_ones(&self) -> Result<(), UnexpectedBits>
+    where
+        Self: Bits,
+    {
+        all_ones(self)
+    }
+    /// The value is an unsigned integer, with all its bits cleared.
+    fn all_zeros(&self) -> Result<(), UnexpectedBits>
+    where
+        Self: Bits,
+    {
+        all_zeros(self)
+    }
+    /// The value is a range, which doesn't start after its end. Ranges with an unbounded side are always valid.
+    fn valid_range<T>(&self) -> Result<(), InvertedRange<'_, T>>
+    where
+        Self: RangeBounds<T>,
+        T: PartialOrd,
+    {
+        match (self.start_bound(), self.end_bound()) {
+            (Bound::Included(start) | Bound::Excluded(start), Bound::Included(end) | Bound::Excluded(end))
+                if start > end =>
+            {
+                Err(InvertedRange { start, end })
+            }
+            _ => Ok(()),
+        }
+    }
+    /// The value is an absolute path.
+    #[cfg(feature = "std")]
+    fn absolute(&self) -> Result<(), RelativePath<'_>>
+    where
+        Self: AsRef<std::path::Path>,
+    {
+        absolute(self)
+    }
+    /// The value is a stream connected to a terminal.
+    #[cfg(feature = "std")]
+    fn a_tty(&self) -> Result<(), TerminalState>
+    where
+        Self: Terminal,
+    {
+        match self.is_terminal() {
+            true => Ok(()),
+            terminal => Err(TerminalState { terminal }),
+        }
+    }
+    /// The value is a stream not connected to a terminal, like a pipe or a file.
+    #[cfg(feature = "std")]
+    fn not_a_tty(&self) -> Result<(), TerminalState>
+    where
+        Self: Terminal,
+    {
+        match self.is_terminal() {
+            false => Ok(()),
+            terminal => Err(TerminalState { terminal }),
+        }
+    }
+    /// The process runs in UTC, whatever the value is.
+    #[cfg(feature = "std")]
+    fn utc(&self) -> Result<(), Timezone> {
+        utc()
+    }
+    /// The value is a string without leading or trailing whitespace.
+    fn trimmed(&self) -> Result<(), Malformed<'_>>
+    where
+        Self: AsRef<str>,
+    {
+        let value = self.as_ref();
+        if value == value.trim() {
+            Ok(())
+        } else {
+            Err(Malformed { value, format: "trimmed" })
+        }
+    }
+    /// The value is a dangling pointer.
+    fn dangling(&self) -> Result<(), NotDangling>
+    where
+        Self: Pointer,
+    {
+        dangling(self.as_const())
+    }
+    /// The value is a string containing a UUID.
+    fn is_uuid(&self) -> Result<(), Malformed<'_>>
+    where
+        Self: AsRef<str>,
+    {
+        let value = self.as_ref();
+        if is_uuid(value) {
+            Ok(())
+        } else {
+            Err(Malformed { value, format: "a UUID" })
+        }
+    }
+    /// The value is a string of hexadecimal digits.
+    fn is_hex(&self) -> Result<(), Malformed<'_>>
+    where
+        Self: AsRef<str>,
+    {
+        let value = self.as_ref();
+        if is_hex(value) {
+            Ok(())
+        } else {
+            Err(Malformed { value, format: "hexadecimal" })
+        }
+    }
+    /// The value is a slice of UTF-16 code units, without unpaired surrogates.
+    fn valid_utf16(&self) -> Result<(), InvalidUtf16>
+    where
+        Self: AsRef<[u16]>,
+    {
+        valid_utf16(self)
+    }
+    /// The value is a closure (or any other value) without borrowed captures, so it is `'static`.
+    ///
+    /// This always holds, since a value which isn't `'static` doesn't compile.
+    /// ```
+    /// # use irrelevant::*;
+    /// let name = String::from("worker");
+    /// let job = move || name.len();
+    /// irrelevant!(job, "The job is spawned onto another thread by the caller.", static_closure);
+    /// ```
+    /// ```compile_fail
+    /// # use irrelevant::*;
+    /// let name = String::from("worker");
+    /// let job = || name.len();
+    /// irrelevant!(job, "The job is spawned onto another thread by the caller.", static_closure);
+    /// # drop(name);
+    /// ```
+    fn static_closure(&self) -> bool
+    where
+        Self: 'static,
+    {
+        true
+    }
+    /// The value is equal to the default value of its type, like the initial state of a state machine.
+    fn is_default(&self) -> Result<(), NotDefault<'_, Self>>
+    where
+        Self: Default + PartialEq + fmt::Debug + Sized,
+    {
+        is_default(self)
+    }
+    /// The value is a number, at most the 99th percentile of the values previously ignored at the same place.
+    #[cfg(feature = "std")]
+    #[track_caller]
+    fn within_p99(&self) -> Result<(), AboveP99>
+    where
+        Self: Copy + Into<f64>,
+    {
+        let caller = core::panic::Location::caller();
+        let site = crate::Location { file: caller.file(), line: caller.line(), column: caller.column() };
+        within_p99(site, *self)
+    }
+    /// The value is equal to the first value of its type ignored at the same place.
+    #[cfg(feature = "std")]
+    #[track_caller]
+    fn deterministic(&self) -> Result<(), Nondeterministic<'_, Self>>
+    where
+        Self: Any + Send + Clone + PartialEq + Sized,
+    {
+        let caller = core::panic::Location::caller();
+        deterministic(Location { file: caller.file(), line: caller.line(), column: caller.column() }, self)
+    }
+    /// The value is a timestamp, not before the latest timestamp ignored with `after_last_global`.
+    #[cfg(target_has_atomic = "64")]
+    fn after_last_global(&self) -> Result<(), ClockRegression>
+    where
+        Self: Copy + Into<u64>,
+    {
+        after_last_global((*self).into())
+    }
+    /// The value upholds the invariant of its type.
+    fn invariant(&self) -> Result<(), BrokenInvariant>
+    where
+        Self: Invariant,
+    {
+        if self.holds() {
+            Ok(())
+        } else {
+            Err(BrokenInvariant { type_name: core::any::type_name::<Self>() })
+        }
+    }
+}
+impl<T: ?Sized> Assumptions for T {}
+
+/// A channel sender that knows how many senders of its channel are still alive.
+///
+/// The channels in `std::sync::mpsc` don't expose the number of their senders, and cloning a `Sender` creates an
+/// independent handle. Because of that, only senders shared through an [`Arc`] can be counted: every clone of the `Arc`
+/// is considered a separate sender. Implement this trait for other channel types to use them with `last_sender`.
+pub trait SenderCount {
+    /// Returns the number of senders that are still alive, including this one.
+    fn sender_count(&self) -> usize;
+}
+#[cfg(feature = "std")]
+impl<T> SenderCount for Arc<mpsc::Sender<T>> {
+    fn sender_count(&self) -> usize {
+        Arc::strong_count(self)
+    }
+}
+#[cfg(feature = "std")]
+impl<T> SenderCount for Arc<mpsc::SyncSender<T>> {
+    fn sender_count(&self) -> usize {
+        Arc::strong_count(self)
+    }
+}
+/// Describes a channel with more than one sender alive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LiveSenders {
+    /// The number of senders that are still alive.
+    pub count: usize,
+}
+impl fmt::Display for LiveSenders {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} senders are alive", self.count)
+    }
+}
+
+/// A collection that knows how many entries it has. Implement this trait for other collections to use them with
+/// `drained`.
+pub trait Collection {
+    /// Returns the number of entries in the collection.
+    fn entries(&self) -> usize;
+}
+impl<T> Collection for [T] {
+    fn entries(&self) -> usize {
+        self.len()
+    }
+}
+impl<T, const N: usize> Collection for [T; N] {
+    fn entries(&self) -> usize {
+        N
+    }
+}
+impl Collection for str {
+    fn entries(&self) -> usize {
+        self.len()
+    }
+}
+impl<C: Collection + ?Sized> Collection for &C {
+    fn entries(&self) -> usize {
+        (**self).entries()
+    }
+}
+impl<C: Collection + ?Sized> Collection for &mut C {
+    fn entries(&self) -> usize {
+        (**self).entries()
+    }
+}
+/// Implements [`Collection`] for collections from the standard library, which all have a `len` method.
+macro_rules! impl_collection {
+    ($($collection:ident<$($param:ident),+>),+) => {
+        $(
+            #[cfg(feature = "std")]
+            impl<$($param),+> Collection for $collection<$($param),+> {
+                fn entries(&self) -> usize {
+                    self.len()
+                }
+            }
+        )+
+    };
+}
+impl_collection!(
+    Vec<T>,
+    VecDeque<T>,
+    LinkedList<T>,
+    BinaryHeap<T>,
+    HashSet<T, S>,
+    BTreeSet<T>,
+    HashMap<K, V, S>,
+    BTreeMap<K, V>
+);
+#[cfg(feature = "std")]
+impl Collection for String {
+    fn entries(&self) -> usize {
+        self.len()
+    }
+}
+/// Describes a collection with entries left in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Remaining {
+    /// The number of entries left.
+    pub entries: usize,
+}
+impl fmt::Display for Remaining {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} entries remain", self.entries)
+    }
+}
+/// A reference-counted pointer. Implement this trait for other pointer types to use them with `unique`.
+pub trait RefCounted {
+    /// Returns the number of strong references to the data, including this one.
+    fn strong_count(&self) -> usize;
+}
+#[cfg(feature = "std")]
+impl<T: ?Sized> RefCounted for Arc<T> {
+    fn strong_count(&self) -> usize {
+        Arc::strong_count(self)
+    }
+}
+#[cfg(feature = "std")]
+impl<T: ?Sized> RefCounted for Rc<T> {
+    fn strong_count(&self) -> usize {
+        Rc::strong_count(self)
+    }
+}
+/// Describes data with more than one strong reference to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StrongReferences {
+    /// The number of strong references.
+    pub count: usize,
+}
+impl fmt::Display for StrongReferences {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} strong references exist", self.count)
+    }
+}
+/// A lock, which can be acquired without blocking. Implement this trait for other locks to use them with
+/// `uncontended`.
+pub trait Lock {
+    /// Tries to acquire the lock without blocking, holding it until this returns.
+    /// Returns `false` if the lock is held elsewhere.
+    ///
+    /// A poisoned lock can still be acquired, so it is not considered contended.
+    fn try_acquire(&self) -> bool;
+}
+#[cfg(feature = "std")]
+impl<T: ?Sized> Lock for Mutex<T> {
+    fn try_acquire(&self) -> bool {
+        !matches!(self.try_lock(), Err(TryLockError::WouldBlock))
+    }
+}
+#[cfg(feature = "std")]
+impl<T: ?Sized> Lock for RwLock<T> {
+    fn try_acquire(&self) -> bool {
+        !matches!(self.try_write(), Err(TryLockError::WouldBlock))
+    }
+}
+#[cfg(feature = "std")]
+impl<L: Lock + ?Sized> Lock for Arc<L> {
+    fn try_acquire(&self) -> bool {
+        (**self).try_acquire()
+    }
+}
+/// Describes a lock held elsewhere.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Contended;
+impl fmt::Display for Contended {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the lock is held elsewhere")
+    }
+}
+/// A queue or stack that can be shared between threads.
+///
+/// This trait is implemented for the standard collections behind a [`Mutex`] or a [`RwLock`]. A poisoned lock is read
+/// anyway, since a panic doesn't remove the items left in the queue. Lock-free queues, like the ones from `crossbeam`,
+/// can implement this trait to use them with `empty`.
+pub trait ConcurrentQueue {
+    /// Checks if there are no items left.
+    fn is_empty(&self) -> bool;
+    /// Returns the number of items left, if it can be cheaply computed.
+    fn remaining(&self) -> Option<usize> {
+        None
+    }
+}
+#[cfg(feature = "std")]
+macro_rules! locked_queue {
+    ($lock:ident, $access:ident, $queue:ident) => {
+        impl<T> ConcurrentQueue for $lock<$queue<T>> {
+            fn is_empty(&self) -> bool {
+                self.$access().unwrap_or_else(PoisonError::into_inner).is_empty()
+            }
+            fn remaining(&self) -> Option<usize> {
+                Some(self.$access().unwrap_or_else(PoisonError::into_inner).len())
+            }
+        }
+    };
+}
+#[cfg(feature = "std")]
+locked_queue!(Mutex, lock, Vec);
+#[cfg(feature = "std")]
+locked_queue!(Mutex, lock, VecDeque);
+#[cfg(feature = "std")]
+locked_queue!(RwLock, read, Vec);
+#[cfg(feature = "std")]
+locked_queue!(RwLock, read, VecDeque);
+#[cfg(feature = "std")]
+impl<Q: ConcurrentQueue + ?Sized> ConcurrentQueue for Arc<Q> {
+    fn is_empty(&self) -> bool {
+        (**self).is_empty()
+    }
+    fn remaining(&self) -> Option<usize> {
+        (**self).remaining()
+    }
+}
+/// Describes a queue with items left in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotEmpty {
+    /// The number of items left, if known.
+    pub remaining: Option<usize>,
+}
+impl fmt::Display for NotEmpty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.remaining {
+            Some(remaining) => write!(f, "{remaining} items remain"),
+            None => write!(f, "items remain"),
+        }
+    }
+}
+/// Checks that `queue` has no items left.
+pub fn empty<Q: ConcurrentQueue + ?Sized>(queue: &Q) -> Result<(), NotEmpty> {
+    if queue.is_empty() {
+        Ok(())
+    } else {
+        Err(NotEmpty {
+            remaining: queue.remaining(),
+        })
+    }
+}
+/// Describes an element that appears more than once in a collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Duplicate<T> {
+    /// The first element found to be a duplicate.
+    pub element: T,
+}
+impl<T: fmt::Debug> fmt::Display for Duplicate<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} appears more than once", self.element)
+    }
+}
+/// Checks that no element appears in `items` more than once.
+#[cfg(feature = "std")]
+pub fn all_unique<T, I>(items: I) -> Result<(), Duplicate<T>>
+where
+    T: Hash + Eq,
+    I: IntoIterator<Item = T>,
+{
+    let mut seen = HashSet::new();
+    for item in items {
+        if let Some(element) = seen.replace(item) {
+            return Err(Duplicate { element });
+        }
+    }
+    Ok(())
+}
+/// A node of a graph, which can list the nodes it points to.
+///
+/// This is usually implemented for a cheap handle to the node, like a wrapper around an `Rc` or `Arc`, with the
+/// address of the node (or some unique name of it) as its id.
+/// ```
+/// # use irrelevant::checks::Traverse;
+/// # use std::{cell::RefCell, rc::Rc};
+/// struct Node {
+///     name: &'static str,
+///     children: RefCell<Vec<NodeRef>>,
+/// }
+/// #[derive(Clone)]
+/// struct NodeRef(Rc<Node>);
+/// impl Traverse for NodeRef {
+///     type Id = &'static str;
+///     fn id(&self) -> Self::Id {
+///         self.0.name
+///     }
+///     fn children(&self) -> Vec<Self> {
+///         self.0.children.borrow().clone()
+///     }
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub trait Traverse: Sized {
+    /// Identifies a node, so revisiting it can be detected.
+    type Id: Hash + Eq + Clone + fmt::Debug;
+    /// Returns the id of this node.
+    fn id(&self) -> Self::Id;
+    /// Returns the nodes this node points to.
+    fn children(&self) -> Vec<Self>;
+}
+/// Describes a cycle in a graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle<Id> {
+    /// The first node found to be revisited while traversing the graph.
+    pub node: Id,
+}
+impl<Id: fmt::Debug> fmt::Display for Cycle<Id> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is part of a cycle", self.node)
+    }
+}
+/// Checks that no cycle is reachable from `root`, by traversing the graph depth-first.
+///
+/// Nodes reachable through more than one path, which don't lead back to themselves, are not cycles.
+#[cfg(feature = "std")]
+pub fn no_cycle<N: Traverse>(root: &N) -> Result<(), Cycle<N::Id>> {
+    // The nodes on the path from the root to the current node, and the nodes with all their children visited.
+    let mut on_path = HashSet::from([root.id()]);
+    let mut finished = HashSet::new();
+    let mut stack = vec![(root.id(), root.children().into_iter())];
+    while let Some((_, children)) = stack.last_mut() {
+        match children.next() {
+            Some(child) => {
+                let id = child.id();
+                if on_path.contains(&id) {
+                    return Err(Cycle { node: id });
+                }
+                if !finished.contains(&id) {
+                    on_path.insert(id.clone());
+                    stack.push((id, child.children().into_iter()));
+                }
+            }
+            None => {
+                let (id, _) = stack.pop().expect("the stack is not empty");
+                on_path.remove(&id);
+                finished.insert(id);
+            }
+        }
+    }
+    Ok(())
+}
+/// Checks if `s` is a UUID in its hyphenated form, like `67e55044-10b1-426f-9247-bb680e5fe0c8`. Both lowercase and
+/// uppercase digits are accepted.
+pub fn is_uuid<S: AsRef<str> + ?Sized>(s: &S) -> bool {
+    let s = s.as_ref().as_bytes();
+    s.len() == 36
+        && s.iter().enumerate().all(|(index, byte)| match index {
+            8 | 13 | 18 | 23 => *byte == b'-',
+            _ => byte.is_ascii_hexdigit(),
+        })
+}
+/// Checks if `s` is a non-empty string of hexadecimal digits, without any prefix.
+pub fn is_hex<S: AsRef<str> + ?Sized>(s: &S) -> bool {
+    let s = s.as_ref();
+    !s.is_empty() && s.bytes().all(|byte| byte.is_ascii_hexdigit())
+}
+/// Describes a string that is not in the expected format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Malformed<'a> {
+    /// The string.
+    pub value: &'a str,
+    /// The expected format, like `a UUID` or `trimmed`.
+    pub format: &'static str,
+}
+impl fmt::Display for Malformed<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is not {}", self.value, self.format)
+    }
+}
+/// A floating-point number, which can be compared in units in the last place (ULPs).
+pub trait Ulps: Copy {
+    /// Returns the number of representable values between `self` and `other`.
+    /// `NaN`s are infinitely far away from everything, including other `NaN`s.
+    fn ulps_between(self, other: Self) -> u64;
+}
+macro_rules! ulps {
+    ($float:ty, $int:ty) => {
+        impl Ulps for $float {
+            fn ulps_between(self, other: Self) -> u64 {
+                if self.is_nan() || other.is_nan() {
+                    return u64::MAX;
+                }
+                // Maps the bit patterns to integers in the same order as the floats they represent.
+                let ordered = |float: $float| {
+                    let bits = float.to_bits() as $int;
+                    if bits < 0 {
+                        <$int>::MIN - bits
+                    } else {
+                        bits
+                    }
+                };
+                ordered(self).abs_diff(ordered(other)) as u64
+            }
+        }
+    };
+}
+ulps!(f32, i32);
+ulps!(f64, i64);
+/// Describes how far away a float is from the expected one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UlpDistance {
+    /// The distance between the values, in ULPs.
+    pub distance: u64,
+    /// The largest distance allowed, in ULPs.
+    pub max: u64,
+}
+impl fmt::Display for UlpDistance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.distance {
+            u64::MAX => write!(f, "a NaN is infinitely many ULPs away, at most {} allowed", self.max),
+            distance => write!(f, "{distance} ULPs away, at most {} allowed", self.max),
+        }
+    }
+}
+/// Checks that `value` is at most `max` ULPs away from `expected`.
+pub fn within_ulps<F: Ulps>(value: F, expected: F, max: u64) -> Result<(), UlpDistance> {
+    let distance = value.ulps_between(expected);
+    if distance <= max {
+        Ok(())
+    } else {
+        Err(UlpDistance { distance, max })
+    }
+}
+/// The address of a socket, or just its IP address. Implement this trait for other address types to use them with
+/// `loopback`.
+#[cfg(feature = "std")]
+pub trait SocketAddress: fmt::Display {
+    /// Returns the IP address.
+    fn ip(&self) -> IpAddr;
+}
+#[cfg(feature = "std")]
+impl SocketAddress for SocketAddr {
+    fn ip(&self) -> IpAddr {
+        self.ip()
+    }
+}
+#[cfg(feature = "std")]
+impl SocketAddress for SocketAddrV4 {
+    fn ip(&self) -> IpAddr {
+        IpAddr::V4(*self.ip())
+    }
+}
+#[cfg(feature = "std")]
+impl SocketAddress for SocketAddrV6 {
+    fn ip(&self) -> IpAddr {
+        IpAddr::V6(*self.ip())
+    }
+}
+#[cfg(feature = "std")]
+impl SocketAddress for IpAddr {
+    fn ip(&self) -> IpAddr {
+        *self
+    }
+}
+#[cfg(feature = "std")]
+impl SocketAddress for Ipv4Addr {
+    fn ip(&self) -> IpAddr {
+        IpAddr::V4(*self)
+    }
+}
+#[cfg(feature = "std")]
+impl SocketAddress for Ipv6Addr {
+    fn ip(&self) -> IpAddr {
+        IpAddr::V6(*self)
+    }
+}
+/// Describes an address which is not on the loopback interface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotLoopback<'a, A: ?Sized> {
+    /// The address.
+    pub address: &'a A,
+}
+impl<A: fmt::Display + ?Sized> fmt::Display for NotLoopback<'_, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} is not a loopback address", self.address)
+    }
+}
+/// Describes a float which is not close to the expected one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NotClose {
+    /// The float.
+    pub value: f64,
+    /// The expected float.
+    pub expected: f64,
+    /// The relative tolerance.
+    pub rtol: f64,
+    /// The absolute tolerance.
+    pub atol: f64,
+}
+impl fmt::Display for NotClose {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { value, expected, rtol, atol } = self;
+        write!(f, "{value} is not close to {expected} (rtol = {rtol}, atol = {atol})")
+    }
+}
+/// Checks that `value` is close to `expected`, like `numpy.isclose`: the difference between them is at most
+/// `atol + rtol * |expected|`.
+///
+/// The absolute tolerance dominates near zero, where any relative tolerance becomes tiny, and the relative tolerance
+/// dominates far away from it. `NaN`s are never close to anything, while infinities are only close to themselves.
+pub fn close_to(value: impl Into<f64>, expected: impl Into<f64>, rtol: f64, atol: f64) -> Result<(), NotClose> {
+    let (value, expected) = (value.into(), expected.into());
+    let abs = |x: f64| if x < 0.0 { -x } else { x };
+    // With an infinity, the difference and the tolerance are both infinite, so non-finite floats are only compared.
+    let close = if value.is_finite() && expected.is_finite() {
+        abs(value - expected) <= atol + rtol * abs(expected)
+    } else {
+        value == expected
+    };
+    if close {
+        Ok(())
+    } else {
+        Err(NotClose {
+            value,
+            expected,
+            rtol,
+            atol,
+        })
+    }
+}
+/// Describes a `Result` which is not the expected `Ok` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnexpectedResult<'a, T, E> {
+    /// The result is `Ok`, but with a different value.
+    Ok {
+        /// The value of the result.
+        actual: &'a T,
+        /// The expected value.
+        expected: &'a T,
+    },
+    /// The result is an `Err`.
+    Err {
+        /// The error of the result.
+        error: &'a E,
+        /// The expected value.
+        expected: &'a T,
+    },
+}
+impl<T: fmt::Debug, E: fmt::Debug> fmt::Display for UnexpectedResult<'_, T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ok { actual, expected } => write!(f, "got Ok({actual:?}), expected Ok({expected:?})"),
+            Self::Err { error, expected } => write!(f, "got Err({error:?}), expected Ok({expected:?})"),
+        }
+    }
+}
+/// Checks that `result` is `Ok`, with a value equal to `expected`.
+pub fn ok_eq<'a, T: PartialEq, E>(result: &'a Result<T, E>, expected: &'a T) -> Result<(), UnexpectedResult<'a, T, E>> {
+    match result {
+        Ok(actual) if actual == expected => Ok(()),
+        Ok(actual) => Err(UnexpectedResult::Ok { actual, expected }),
+        Err(error) => Err(UnexpectedResult::Err { error, expected }),
+    }
+}
+/// Describes an `Option` which is `Some` while it is expected to be `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnexpectedSome<'a, T> {
+    /// The value of the option.
+    pub value: &'a T,
+}
+impl<T: fmt::Debug> fmt::Display for UnexpectedSome<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "got Some({:?}), expected None", self.value)
+    }
+}
+/// Checks that `option` is `None` if `cond` is true. Any option is accepted otherwise.
+pub fn none_when<T>(option: &Option<T>, cond: bool) -> Result<(), UnexpectedSome<'_, T>> {
+    match option {
+        Some(value) if cond => Err(UnexpectedSome { value }),
+        _ => Ok(()),
+    }
+}
+/// Describes allocations made since an [`AllocMarker`](crate::AllocMarker).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Allocated {
+    /// The number of allocations.
+    pub allocations: u64,
+}
+impl fmt::Display for Allocated {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} allocations were made since the marker", self.allocations)
+    }
+}
+/// Checks that the current thread made no allocations since `marker`. Requires the `alloc-counter` feature.
+#[cfg(feature = "alloc-counter")]
+pub fn no_alloc_since(marker: &crate::AllocMarker) -> Result<(), Allocated> {
+    match marker.allocations_since() {
+        0 => Ok(()),
+        allocations => Err(Allocated { allocations }),
+    }
+}
+/// Describes a value outside of the expected range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotWithin<'a, T, R> {
+    /// The value.
+    pub value: &'a T,
+    /// The range.
+    pub range: &'a R,
+}
+impl<T: fmt::Debug, R: fmt::Debug> fmt::Display for NotWithin<'_, T, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is not in {:?}", self.value, self.range)
+    }
+}
+/// Checks that `value` is in `range`, which can be any kind of range, like `a..b`, `a..=b`, `..=b` or `..`.
+pub fn within<'a, T: PartialOrd, R: RangeBounds<T>>(value: &'a T, range: &'a R) -> Result<(), NotWithin<'a, T, R>> {
+    if range.contains(value) {
+        Ok(())
+    } else {
+        Err(NotWithin { value, range })
+    }
+}
+/// Describes a pointer outside of the expected memory range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfRange {
+    /// The pointer.
+    pub ptr: *const u8,
+    /// The start of the range.
+    pub start: *const u8,
+    /// The end of the range, exclusive.
+    pub end: *const u8,
+}
+impl fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:p} is outside of {:p}..{:p}", self.ptr, self.start, self.end)
+    }
+}
+/// Checks that `ptr` points into the memory range from `start` up to, but not including, `end`.
+/// Only the addresses are compared, so the pointers may have different types.
+pub fn in_range<T: ?Sized, U: ?Sized>(ptr: *const T, start: *const U, end: *const U) -> Result<(), OutOfRange> {
+    let (ptr, start, end) = (ptr.cast::<u8>(), start.cast::<u8>(), end.cast::<u8>());
+    if (start..end).contains(&ptr) {
+        Ok(())
+    } else {
+        Err(OutOfRange { ptr, start, end })
+    }
+}
+/// Describes the timezone the process runs in, when it is not the expected one.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timezone {
+    /// The value of the `TZ` environment variable, if it is set.
+    pub tz: Option<String>,
+}
+#[cfg(feature = "std")]
+impl fmt::Display for Timezone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.tz {
+            Some(tz) => write!(f, "TZ is {tz:?}"),
+            None => write!(f, "TZ is not set"),
+        }
+    }
+}
+/// Returns the timezone from the `TZ` environment variable, without the optional leading `:`.
+#[cfg(feature = "std")]
+fn timezone() -> Result<String, Timezone> {
+    match std::env::var("TZ") {
+        Ok(tz) => Ok(tz.strip_prefix(':').map(str::to_owned).unwrap_or(tz)),
+        Err(_) => Err(Timezone { tz: None }),
+    }
+}
+/// Checks that the process runs in UTC.
+///
+/// The timezone is read from the `TZ` environment variable. When it is not set, the system timezone is used, which
+/// can't be checked portably, so it is always considered a violation. `UTC`, `UTC0`, `Etc/UTC`, `GMT`, `GMT0` and
+/// `Etc/GMT` are all accepted as UTC.
+#[cfg(feature = "std")]
+pub fn utc() -> Result<(), Timezone> {
+    let tz = timezone()?;
+    match tz.as_str() {
+        "UTC" | "UTC0" | "Etc/UTC" | "GMT" | "GMT0" | "Etc/GMT" => Ok(()),
+        _ => Err(Timezone { tz: Some(tz) }),
+    }
+}
+/// Checks that the process runs in the timezone `expected`, as set by the `TZ` environment variable.
+/// When `TZ` is not set, the system timezone is used, which can't be checked, so it is always considered a violation.
+#[cfg(feature = "std")]
+pub fn tz_is(expected: &str) -> Result<(), Timezone> {
+    let tz = timezone()?;
+    if tz == expected {
+        Ok(())
+    } else {
+        Err(Timezone { tz: Some(tz) })
+    }
+}
+/// Describes an environment variable with an unexpected value.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Environment<'a> {
+    /// The name of the environment variable.
+    pub var: &'a str,
+    /// The value of the environment variable, if it is set.
+    pub value: Option<String>,
+}
+#[cfg(feature = "std")]
+impl fmt::Display for Environment<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            Some(value) => write!(f, "{} is {value:?}", self.var),
+            None => write!(f, "{} is not set", self.var),
+        }
+    }
+}
+/// Checks that the environment variable `var` is set to `expected`, like `APP_ENV` set to `production`.
+#[cfg(feature = "std")]
+pub fn env_is<'a>(var: &'a str, expected: &str) -> Result<(), Environment<'a>> {
+    match std::env::var(var) {
+        Ok(value) if value == expected => Ok(()),
+        value => Err(Environment { var, value: value.ok() }),
+    }
+}
+/// A point in time, which can tell how long after the Unix epoch it is.
+///
+/// This is implemented for `SystemTime`, and for `Duration`, as the time since the epoch.
+pub trait UnixTime {
+    /// Returns the duration since the Unix epoch, or `None` if this is before it.
+    fn since_epoch(&self) -> Option<core::time::Duration>;
+}
+impl UnixTime for core::time::Duration {
+    fn since_epoch(&self) -> Option<core::time::Duration> {
+        Some(*self)
+    }
+}
+#[cfg(feature = "std")]
+impl UnixTime for std::time::SystemTime {
+    fn since_epoch(&self) -> Option<core::time::Duration> {
+        self.duration_since(std::time::UNIX_EPOCH).ok()
+    }
+}
+impl<T: UnixTime + ?Sized> UnixTime for &T {
+    fn since_epoch(&self) -> Option<core::time::Duration> {
+        (**self).since_epoch()
+    }
+}
+/// Describes a point in time before the expected one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooEarly {
+    /// The number of whole seconds since the Unix epoch, or `None` if the time is before it.
+    pub secs: Option<u64>,
+    /// The earliest expected number of seconds since the Unix epoch.
+    pub min_secs: u64,
+}
+impl fmt::Display for TooEarly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.secs {
+            Some(secs) => write!(f, "{secs} seconds since the epoch, expected at least {}", self.min_secs),
+            None => write!(f, "before the epoch, expected at least {} seconds after it", self.min_secs),
+        }
+    }
+}
+/// Checks that `time` is at least `min_secs` seconds after the Unix epoch.
+pub fn after<T: UnixTime + ?Sized>(time: &T, min_secs: u64) -> Result<(), TooEarly> {
+    let secs = time.since_epoch().map(|since| since.as_secs());
+    match secs {
+        Some(secs) if secs >= min_secs => Ok(()),
+        secs => Err(TooEarly { secs, min_secs }),
+    }
+}
+/// A value, which may be accompanied by a witness token.
+///
+/// Witness tokens are types with private constructors, so only sanctioned code can create them. Pairing a value with
+/// a token documents, and allows checking, where the value came from. The type of the token is checked at compile
+/// time, and its presence is checked at runtime, using `witness(Token)`.
+/// ```
+/// # use irrelevant::*;
+/// # use irrelevant::checks::Witnessed;
+/// mod config {
+///     # use irrelevant::checks::Witnessed;
+///     pub struct Validated(());
+///     pub struct Config;
+///     pub fn load() -> Witnessed<Config, Validated> {
+///         Witnessed::new(Config, Validated(()))
+///     }
+/// }
+/// let config = config::load();
+/// irrelevant!(config, "The config was validated when loading it.", witness(config::Validated));
+/// ```
+/// The token type must match:
+/// ```compile_fail
+/// # use irrelevant::*;
+/// # use irrelevant::checks::Witnessed;
+/// # struct Validated(());
+/// # struct Parsed(());
+/// let config = Witnessed::new((), Parsed(()));
+/// irrelevant!(config, "The config was validated when loading it.", witness(Validated));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Witnessed<T, W> {
+    value: T,
+    witness: Option<W>,
+}
+impl<T, W> Witnessed<T, W> {
+    /// Pairs the value with a witness token.
+    pub fn new(value: T, witness: W) -> Self {
+        Self {
+            value,
+            witness: Some(witness),
+        }
+    }
+    /// Wraps a value without a witness token.
+    pub fn unwitnessed(value: T) -> Self {
+        Self { value, witness: None }
+    }
+    /// Returns the witness token accompanying the value, if any.
+    pub fn witness(&self) -> Option<&W> {
+        self.witness.as_ref()
+    }
+    /// Returns the wrapped value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+    /// Unwraps the value, discarding the witness token.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+/// A fieldless enum, which can tell if an integer is one of its discriminants.
+///
+/// Enums defined with [`checked_enum`](crate::checked_enum) implement this trait automatically, so their discriminants
+/// never get out of sync with the checks.
+pub trait Discriminant {
+    /// The integer type the enum is represented as.
+    type Repr;
+    /// Checks if `value` is a discriminant of one of the variants.
+    fn is_discriminant(value: Self::Repr) -> bool;
+}
+/// Defines a fieldless enum implementing [`Discriminant`], for checking integers using `valid_variant: Enum`.
+///
+/// The enum must start with a `#[repr]` attribute, selecting the integer type used for its discriminants.
+/// ```
+/// # use irrelevant::*;
+/// checked_enum! {
+///     #[repr(u8)]
+///     #[derive(Debug)]
+///     pub enum Mode {
+///         Read = 1,
+///         Write = 2,
+///         Append,
+///     }
+/// }
+/// /// Called through FFI, with a mode from the C side.
+/// extern "C" fn set_mode(mode: u8) {
+///     irrelevant!(mode, "Only the read mode is supported, and others are rejected by the C side.", valid_variant: Mode);
+/// }
+/// # set_mode(3);
+/// ```
+#[macro_export]
+macro_rules! checked_enum {
+    (
+        #[repr($repr:ident)]
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident $(= $discriminant:expr)?),* $(,)?
+        }
+    ) => {
+        #[repr($repr)]
+        $(#[$meta])*
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant $(= $discriminant)?),*
+        }
+        impl $crate::checks::Discriminant for $name {
+            type Repr = $repr;
+            fn is_discriminant(value: $repr) -> bool {
+                [$($name::$variant as $repr),*].contains(&value)
+            }
+        }
+    };
+}
+/// Describes an unexpected integer read from a byte buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BytesMismatch {
+    /// The integer read, or `None` if the buffer was too short.
+    pub actual: Option<u32>,
+    /// The expected integer.
+    pub expected: u32,
+}
+impl fmt::Display for BytesMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.actual {
+            Some(actual) => write!(f, "read {actual:#010x}, expected {:#010x}", self.expected),
+            None => write!(f, "fewer than 4 bytes, expected {:#010x}", self.expected),
+        }
+    }
+}
+fn read_u32<B: AsRef<[u8]> + ?Sized>(
+    bytes: &B,
+    expected: u32,
+    from_bytes: fn([u8; 4]) -> u32,
+) -> Result<(), BytesMismatch> {
+    let actual = bytes
+        .as_ref()
+        .get(..4)
+        .map(|start| from_bytes(start.try_into().expect("the slice is 4 bytes long")));
+    if actual == Some(expected) {
+        Ok(())
+    } else {
+        Err(BytesMismatch { actual, expected })
+    }
+}
+/// Checks that the first 4 bytes of `bytes` are `expected`, in big-endian byte order.
+pub fn be_u32<B: AsRef<[u8]> + ?Sized>(bytes: &B, expected: u32) -> Result<(), BytesMismatch> {
+    read_u32(bytes, expected, u32::from_be_bytes)
+}
+/// Checks that the first 4 bytes of `bytes` are `expected`, in little-endian byte order.
+pub fn le_u32<B: AsRef<[u8]> + ?Sized>(bytes: &B, expected: u32) -> Result<(), BytesMismatch> {
+    read_u32(bytes, expected, u32::from_le_bytes)
+}
+/// A validator, which checks a value of any type. See [`register_validator`].
+#[cfg(feature = "std")]
+pub type Validator = fn(&dyn Any) -> bool;
+#[cfg(feature = "std")]
+static VALIDATORS: Mutex<Option<HashMap<&'static str, Validator>>> = Mutex::new(None);
+/// Registers a validator under `name`, replacing any validator registered under the same name before.
+///
+/// Validators receive the ignored value as [`Any`], and should return `false` both when the value is invalid, and when
+/// it has an unexpected type. This allows centralizing complex validation logic, and reusing it with
+/// `validator(name)`.
+/// ```
+/// # use irrelevant::*;
+/// # use std::any::Any;
+/// fn is_port(value: &dyn Any) -> bool {
+///     value.downcast_ref::<u16>().is_some_and(|port| *port != 0)
+/// }
+/// register_validator("port", is_port);
+/// let port: u16 = 8080;
+/// irrelevant!(port, "The port is only used by the server.", validator("port"));
+/// ```
+#[cfg(feature = "std")]
+pub fn register_validator(name: &'static str, validator: Validator) {
+    VALIDATORS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get_or_insert_with(HashMap::new)
+        .insert(name, validator);
+}
+/// Describes a value rejected by a validator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidatorFailure<'a> {
+    /// The name of the validator.
+    pub name: &'a str,
+    /// Whether a validator with this name was registered.
+    pub registered: bool,
+}
+impl fmt::Display for ValidatorFailure<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.registered {
+            write!(f, "rejected by the {:?} validator", self.name)
+        } else {
+            write!(f, "no {:?} validator is registered", self.name)
+        }
+    }
+}
+/// Checks that `value` passes the validator registered as `name`, using [`register_validator`].
+#[cfg(feature = "std")]
+pub fn validator<'a, T: Any>(name: &'a str, value: &T) -> Result<(), ValidatorFailure<'a>> {
+    let validator = VALIDATORS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .as_ref()
+        .and_then(|validators| validators.get(name).copied());
+    match validator {
+        Some(validator) if validator(value) => Ok(()),
+        Some(_) => Err(ValidatorFailure { name, registered: true }),
+        None => Err(ValidatorFailure { name, registered: false }),
+    }
+}
+/// A raw pointer to a sized type. Implement this trait for other pointer types to use them with `dangling`.
+pub trait Pointer {
+    /// The type the pointer points to.
+    type Pointee;
+    /// Returns the pointer as a `*const` pointer.
+    fn as_const(&self) -> *const Self::Pointee;
+}
+impl<T> Pointer for *const T {
+    type Pointee = T;
+    fn as_const(&self) -> *const T {
+        *self
+    }
+}
+impl<T> Pointer for *mut T {
+    type Pointee = T;
+    fn as_const(&self) -> *const T {
+        self.cast_const()
+    }
+}
+/// A value registered with [`register_baseline`](crate::register_baseline).
+#[cfg(feature = "std")]
+struct Baseline {
+    value: Box<dyn Any + Send>,
+    /// The `Debug` representation of the value, reported when an ignored value diverges from it.
+    debug: String,
+    location: Location,
+}
+#[cfg(feature = "std")]
+static BASELINES: Mutex<Option<HashMap<&'static str, Baseline>>> = Mutex::new(None);
+#[doc(hidden)]
+#[cfg(feature = "std")]
+pub fn __register_baseline<T: Any + Send + fmt::Debug>(key: &'static str, value: T, location: Location) {
+    let debug = format!("{value:?}");
+    BASELINES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get_or_insert_with(HashMap::new)
+        .insert(key, Baseline { value: Box::new(value), debug, location });
+}
+/// Describes a value which doesn't match the baseline it is compared against.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum BaselineMismatch<'a, T: ?Sized> {
+    /// No baseline was registered under the key.
+    Unregistered(&'a str),
+    /// The baseline registered under the key has a different type than the value.
+    Type {
+        /// The key of the baseline.
+        key: &'a str,
+        /// Where the baseline was registered.
+        location: Location,
+    },
+    /// The value differs from the baseline.
+    Diverged {
+        /// The key of the baseline.
+        key: &'a str,
+        /// The value.
+        actual: &'a T,
+        /// The `Debug` representation of the baseline.
+        baseline: String,
+        /// Where the baseline was registered.
+        location: Location,
+    },
+}
+#[cfg(feature = "std")]
+impl<T: fmt::Debug + ?Sized> fmt::Display for BaselineMismatch<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unregistered(key) => write!(f, "no {key:?} baseline is registered"),
+            Self::Type { key, location } => write!(
+                f,
+                "the {key:?} baseline registered at {}:{}:{} has a different type",
+                location.file, location.line, location.column
+            ),
+            Self::Diverged { key, actual, baseline, location } => write!(
+                f,
+                "{actual:?} diverged from the {key:?} baseline {baseline}, registered at {}:{}:{}",
+                location.file, location.line, location.column
+            ),
+        }
+    }
+}
+/// Checks that `value` is equal to the baseline registered under `key`, using
+/// [`register_baseline`](crate::register_baseline).
+#[cfg(feature = "std")]
+pub fn matches_baseline<'a, T: Any + PartialEq>(key: &'a str, value: &'a T) -> Result<(), BaselineMismatch<'a, T>> {
+    let baselines = BASELINES.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let Some(baseline) = baselines.as_ref().and_then(|baselines| baselines.get(key)) else {
+        return Err(BaselineMismatch::Unregistered(key));
+    };
+    let location = baseline.location;
+    match baseline.value.downcast_ref::<T>() {
+        Some(expected) if expected == value => Ok(()),
+        Some(_) => Err(BaselineMismatch::Diverged {
+            key,
+            actual: value,
+            baseline: baseline.debug.clone(),
+            location,
+        }),
+        None => Err(BaselineMismatch::Type { key, location }),
+    }
+}
+/// Describes a pointer which is not dangling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotDangling {
+    /// The pointer.
+    pub ptr: *const u8,
+    /// The address of a dangling pointer of the same type.
+    pub dangling: *const u8,
+}
+impl fmt::Display for NotDangling {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:p} is not the dangling pointer {:p}", self.ptr, self.dangling)
+    }
+}
+/// Checks that `ptr` is dangling, which means its address is the alignment of `T`, just like the address of
+/// [`NonNull::dangling`](core::ptr::NonNull::dangling). This is the conventional pointer to zero-sized types.
+pub fn dangling<T>(ptr: *const T) -> Result<(), NotDangling> {
+    let dangling = core::ptr::NonNull::<T>::dangling().as_ptr().cast_const();
+    if ptr == dangling {
+        Ok(())
+    } else {
+        Err(NotDangling {
+            ptr: ptr.cast(),
+            dangling: dangling.cast(),
+        })
+    }
+}
+/// Describes an unexpected number of items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CountMismatch {
+    /// The number of items.
+    pub actual: usize,
+    /// The expected number of items.
+    pub expected: usize,
+}
+impl fmt::Display for CountMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "yielded {} items, expected {}", self.actual, self.expected)
+    }
+}
+/// Checks that `items` yields exactly `expected` items.
+///
+/// This consumes the iterator, running any side effects it has. When the assumption is only checked in debug mode,
+/// with [`debug_irrelevant`](crate::debug_irrelevant), the iterator is not consumed in release mode.
+pub fn count<I: IntoIterator>(items: I, expected: usize) -> Result<(), CountMismatch> {
+    let actual = items.into_iter().count();
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(CountMismatch { actual, expected })
+    }
+}
+/// A type with an atomic counterpart in [`core::sync::atomic`].
+pub trait HasAtomic {
+    /// The atomic version of this type.
+    type Atomic;
+}
+macro_rules! has_atomic {
+    ($($width:literal: $($ty:ty => $atomic:ident),+;)+) => {
+        $($(
+            #[cfg(target_has_atomic = $width)]
+            impl HasAtomic for $ty {
+                type Atomic = core::sync::atomic::$atomic;
+            }
+        )+)+
+    };
+}
+has_atomic! {
+    "8": bool => AtomicBool, u8 => AtomicU8, i8 => AtomicI8;
+    "16": u16 => AtomicU16, i16 => AtomicI16;
+    "32": u32 => AtomicU32, i32 => AtomicI32;
+    "64": u64 => AtomicU64, i64 => AtomicI64;
+    "ptr": usize => AtomicUsize, isize => AtomicIsize;
+}
+#[cfg(target_has_atomic = "ptr")]
+impl<T> HasAtomic for *mut T {
+    type Atomic = core::sync::atomic::AtomicPtr<T>;
+}
+/// Describes a misaligned pointer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Misaligned {
+    /// The pointer.
+    pub ptr: *const u8,
+    /// The required alignment.
+    pub align: usize,
+}
+impl fmt::Display for Misaligned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:p} is not aligned to {} bytes", self.ptr, self.align)
+    }
+}
+/// Checks that `ptr` is suitably aligned for accessing a `T` atomically, through `T::Atomic`.
+///
+/// An atomic type can have a stricter alignment requirement than the plain one. For example, `u64` is only aligned to
+/// 4 bytes on some 32-bit targets, while `AtomicU64` is always aligned to 8 bytes.
+pub fn atomic_aligned<T: HasAtomic, P: ?Sized>(ptr: *const P) -> Result<(), Misaligned> {
+    let ptr = ptr.cast::<u8>();
+    let align = core::mem::align_of::<T::Atomic>();
+    if ptr as usize & (align - 1) == 0 {
+        Ok(())
+    } else {
+        Err(Misaligned { ptr, align })
+    }
+}
+/// Describes a string without any of the expected prefixes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MissingPrefix<'a> {
+    /// The string.
+    pub value: &'a str,
+}
+impl fmt::Display for MissingPrefix<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} starts with none of the prefixes", self.value)
+    }
+}
+/// Checks that `value` starts with at least one of `prefixes`.
+pub fn starts_with_any<'a, S, I>(value: &'a S, prefixes: I) -> Result<(), MissingPrefix<'a>>
+where
+    S: AsRef<str> + ?Sized,
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let value = value.as_ref();
+    if prefixes.into_iter().any(|prefix| value.starts_with(prefix.as_ref())) {
+        Ok(())
+    } else {
+        Err(MissingPrefix { value })
+    }
+}
+/// Asserts at compile time that `T` has the same size and alignment as `U`.
+///
+/// The assertion fails when the function is instantiated with incompatible types, so the error shows up when building,
+/// not when type checking alone (for example, with `cargo check`).
+/// ```
+/// # use irrelevant::*;
+/// #[repr(transparent)]
+/// struct Meters(f64);
+/// let distance = Meters(3.0);
+/// irrelevant!(distance, "The distance is only forwarded as a raw float.", same_layout_as f64);
+/// ```
+/// ```compile_fail
+/// # use irrelevant::*;
+/// struct Point(f64, f64);
+/// let point = Point(3.0, 4.0);
+/// irrelevant!(point, "The point is only forwarded as a raw float.", same_layout_as f64);
+/// ```
+/// ```compile_fail
+/// # use irrelevant::*;
+/// let bytes = [0u8; 8];
+/// irrelevant!(bytes, "The bytes are only forwarded as a raw float.", same_layout_as f64);
+/// ```
+pub fn same_layout_as<U, T>(_value: &T) {
+    const {
+        assert!(
+            core::mem::size_of::<T>() == core::mem::size_of::<U>()
+                && core::mem::align_of::<T>() == core::mem::align_of::<U>(),
+            "the types have different layouts"
+        );
+    }
+}
+/// Describes a value which doesn't match its snapshot.
+#[cfg(feature = "snapshot")]
+#[derive(Debug)]
+pub enum SnapshotMismatch {
+    /// The value differs from the snapshot.
+    Differs {
+        /// The stored snapshot.
+        expected: String,
+        /// The snapshot of the value.
+        actual: String,
+    },
+    /// The snapshot could not be read or written.
+    Io(std::io::Error),
+}
+#[cfg(feature = "snapshot")]
+impl fmt::Display for SnapshotMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(not(feature = "pretty"))]
+            Self::Differs { expected, actual } => {
+                write!(f, "the value differs from its snapshot, expected {expected}, got {actual}")
+            }
+            #[cfg(feature = "pretty")]
+            Self::Differs { expected, actual } => {
+                write!(f, "the value differs from its snapshot:\n{}", Diff { expected, actual })
+            }
+            Self::Io(err) => write!(f, "the snapshot could not be accessed: {err}"),
+        }
+    }
+}
+/// Checks that the `Debug` representation of `value` matches the snapshot stored in the file at `path`.
+///
+/// When the file doesn't exist yet, the snapshot of the value is written to it, and the check passes. To update a
+/// snapshot, delete its file. The pretty-printed (`{:#?}`) representation is used, so snapshots are easy to review.
+#[cfg(feature = "snapshot")]
+pub fn snapshot<T: fmt::Debug, P: AsRef<std::path::Path>>(value: &T, path: P) -> Result<(), SnapshotMismatch> {
+    let path = path.as_ref();
+    let actual = format!("{value:#?}");
+    match std::fs::read_to_string(path) {
+        Ok(expected) if expected == actual => Ok(()),
+        Ok(expected) => Err(SnapshotMismatch::Differs { expected, actual }),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            std::fs::write(path, actual).map_err(SnapshotMismatch::Io)
+        }
+        Err(err) => Err(SnapshotMismatch::Io(err)),
+    }
+}
+/// The lookup table for [`crc32`], for the reversed polynomial `0xEDB88320`.
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut byte = 0;
+    while byte < 256 {
+        let mut crc = byte as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+            bit += 1;
+        }
+        table[byte] = crc;
+        byte += 1;
+    }
+    table
+};
+/// Computes the CRC-32 checksum of `bytes`, as used by zlib, PNG and Ethernet (CRC-32/ISO-HDLC).
+/// ```
+/// assert_eq!(irrelevant::checks::crc32(b"123456789"), 0xCBF4_3926);
+/// ```
+pub fn crc32<B: AsRef<[u8]> + ?Sized>(bytes: &B) -> u32 {
+    !bytes.as_ref().iter().fold(!0, |crc, byte| {
+        CRC32_TABLE[usize::from((crc as u8) ^ byte)] ^ (crc >> 8)
+    })
+}
+/// A bitset, which can count its set bits.
+///
+/// This is implemented for integers, and for slices, arrays and vectors of them, which act as larger bitsets.
+pub trait Popcount {
+    /// Returns the number of set bits.
+    fn popcount(&self) -> u64;
+}
+macro_rules! popcount {
+    ($($int:ty),+) => {
+        $(
+            impl Popcount for $int {
+                fn popcount(&self) -> u64 {
+                    u64::from(self.count_ones())
+                }
+            }
+        )+
+    };
+}
+popcount!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl<T: Popcount> Popcount for [T] {
+    fn popcount(&self) -> u64 {
+        self.iter().map(Popcount::popcount).sum()
+    }
+}
+impl<T: Popcount, const N: usize> Popcount for [T; N] {
+    fn popcount(&self) -> u64 {
+        self.as_slice().popcount()
+    }
+}
+#[cfg(feature = "std")]
+impl<T: Popcount> Popcount for Vec<T> {
+    fn popcount(&self) -> u64 {
+        self.as_slice().popcount()
+    }
+}
+impl<T: Popcount + ?Sized> Popcount for &T {
+    fn popcount(&self) -> u64 {
+        (**self).popcount()
+    }
+}
+/// An unsigned integer, like a hardware register, whose bits can be checked by `all_ones` and `all_zeros`.
+pub trait Bits {
+    /// The number of bits.
+    const BITS: u32;
+    /// Returns the bits, zero-extended.
+    fn bits(&self) -> u128;
+}
+macro_rules! bits {
+    ($($int:ty),+) => {
+        $(
+            impl Bits for $int {
+                const BITS: u32 = <$int>::BITS;
+                fn bits(&self) -> u128 {
+                    *self as u128
+                }
+            }
+        )+
+    };
+}
+bits!(u8, u16, u32, u64, u128, usize);
+impl<T: Bits + ?Sized> Bits for &T {
+    const BITS: u32 = T::BITS;
+    fn bits(&self) -> u128 {
+        (**self).bits()
+    }
+}
+/// Describes an integer, whose bits are not all ones or all zeros.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnexpectedBits {
+    /// The bits of the integer, zero-extended.
+    pub bits: u128,
+    /// The number of bits of the integer.
+    pub width: u32,
+    /// Whether all the bits were expected to be ones, rather than zeros.
+    pub ones: bool,
+}
+impl fmt::Display for UnexpectedBits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // All the digits are shown, so it is clear which bits are wrong. The `0x` prefix counts towards the width.
+        let width = self.width as usize / 4 + 2;
+        let expected = if self.ones { "ones" } else { "zeros" };
+        write!(f, "{:#0width$x} is not all {expected}", self.bits)
+    }
+}
+/// Checks that all the bits of `value` are ones.
+pub fn all_ones<T: Bits + ?Sized>(value: &T) -> Result<(), UnexpectedBits> {
+    let (bits, width) = (value.bits(), T::BITS);
+    if bits == u128::MAX >> (u128::BITS - width) {
+        Ok(())
+    } else {
+        Err(UnexpectedBits { bits, width, ones: true })
+    }
+}
+/// Checks that all the bits of `value` are zeros.
+pub fn all_zeros<T: Bits + ?Sized>(value: &T) -> Result<(), UnexpectedBits> {
+    let (bits, width) = (value.bits(), T::BITS);
+    if bits == 0 {
+        Ok(())
+    } else {
+        Err(UnexpectedBits { bits, width, ones: false })
+    }
+}
+/// A collection, which can estimate how much heap memory it uses.
+///
+/// The estimate is the capacity of the collection times the size of its elements. It doesn't include the bookkeeping
+/// of the allocator or of the collection itself, or the memory owned by the elements.
+#[cfg(feature = "std")]
+pub trait HeapBytes {
+    /// Returns the estimated number of bytes allocated by the collection.
+    fn heap_bytes(&self) -> usize;
+}
+#[cfg(feature = "std")]
+macro_rules! heap_bytes {
+    ($($collection:ident<$($param:ident),+> => $element:ty),+) => {
+        $(
+            impl<$($param),+> HeapBytes for $collection<$($param),+> {
+                fn heap_bytes(&self) -> usize {
+                    self.capacity().saturating_mul(core::mem::size_of::<$element>())
+                }
+            }
+        )+
+    };
+}
+#[cfg(feature = "std")]
+heap_bytes!(
+    Vec<T> => T,
+    VecDeque<T> => T,
+    BinaryHeap<T> => T,
+    HashSet<T> => T,
+    HashMap<K, V> => (K, V)
+);
+#[cfg(feature = "std")]
+impl HeapBytes for String {
+    fn heap_bytes(&self) -> usize {
+        self.capacity()
+    }
+}
+#[cfg(feature = "std")]
+impl<T: HeapBytes + ?Sized> HeapBytes for &T {
+    fn heap_bytes(&self) -> usize {
+        (**self).heap_bytes()
+    }
+}
+/// A stream, which may be connected to a terminal.
+///
+/// This is implemented for everything implementing [`IsTerminal`](std::io::IsTerminal), like `Stdin` and `Stdout`.
+/// Since `IsTerminal` can't be implemented outside of the standard library, this trait can also be implemented for
+/// other streams, or for mocks in tests.
+#[cfg(feature = "std")]
+pub trait Terminal {
+    /// Checks if the stream is connected to a terminal.
+    fn is_terminal(&self) -> bool;
+}
+#[cfg(feature = "std")]
+impl<T: std::io::IsTerminal> Terminal for T {
+    fn is_terminal(&self) -> bool {
+        std::io::IsTerminal::is_terminal(self)
+    }
+}
+/// Describes a stream, which is or isn't connected to a terminal, unlike expected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerminalState {
+    /// Whether the stream is connected to a terminal.
+    pub terminal: bool,
+}
+impl fmt::Display for TerminalState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.terminal {
+            f.write_str("it is a terminal")
+        } else {
+            f.write_str("it is not a terminal")
+        }
+    }
+}
+/// Describes an implausible reading.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Implausible<T> {
+    /// The reading.
+    pub reading: T,
+    /// The smallest plausible reading.
+    pub min: T,
+    /// The largest plausible reading.
+    pub max: T,
+}
+impl<T: fmt::Debug> fmt::Display for Implausible<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the reading {:?} is outside of {:?}..={:?}", self.reading, self.min, self.max)
+    }
+}
+/// Checks that `reading` is between `min` and `max`, inclusive.
+///
+/// Since `NaN`s are not comparable, they are never plausible, which makes this suitable for sensor readings.
+/// Describes an unpaired surrogate found in a slice of UTF-16 code units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidUtf16 {
+    /// The index of the first invalid code unit.
+    pub index: usize,
+    /// The invalid code unit.
+    pub unit: u16,
+}
+impl fmt::Display for InvalidUtf16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "found the unpaired surrogate {:#06x} at {}", self.unit, self.index)
+    }
+}
+/// Checks that `units` are valid UTF-16, decoding them with [`char::decode_utf16`].
+pub fn valid_utf16<U: AsRef<[u16]> + ?Sized>(units: &U) -> Result<(), InvalidUtf16> {
+    let mut index = 0;
+    for decoded in char::decode_utf16(units.as_ref().iter().copied()) {
+        match decoded {
+            Ok(c) => index += c.len_utf16(),
+            Err(err) => return Err(InvalidUtf16 { index, unit: err.unpaired_surrogate() }),
+        }
+    }
+    Ok(())
+}
+pub fn plausible<T: PartialOrd>(reading: T, min: T, max: T) -> Result<(), Implausible<T>> {
+    if min <= reading && reading <= max {
+        Ok(())
+    } else {
+        Err(Implausible { reading, min, max })
+    }
+}
+/// Describes a path which is not absolute.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelativePath<'a> {
+    /// The path.
+    pub path: &'a std::path::Path,
+}
+#[cfg(feature = "std")]
+impl fmt::Display for RelativePath<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is not absolute", self.path)
+    }
+}
+/// Checks that `path` is absolute, using [`Path::is_absolute`](std::path::Path::is_absolute).
+#[cfg(feature = "std")]
+pub fn absolute<P: AsRef<std::path::Path> + ?Sized>(path: &P) -> Result<(), RelativePath<'_>> {
+    let path = path.as_ref();
+    if path.is_absolute() {
+        Ok(())
+    } else {
+        Err(RelativePath { path })
+    }
+}
+/// Describes a path which is not within its root directory.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutsideRoot<'a> {
+    /// The path.
+    pub path: &'a std::path::Path,
+    /// What the path resolves to.
+    pub resolved: std::path::PathBuf,
+    /// The root directory.
+    pub root: std::path::PathBuf,
+}
+#[cfg(feature = "std")]
+impl fmt::Display for OutsideRoot<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} resolves to {:?}, outside of {:?}", self.path, self.resolved, self.root)
+    }
+}
+/// Resolves `path` one component at a time, following symbolic links for the parts of it which exist.
+///
+/// A `..` component goes to the parent of what the path resolved to so far, so it can't be used to step back out of
+/// a symbolic link. The parts which don't exist yet (like a file about to be created) are only normalized.
+#[cfg(feature = "std")]
+fn resolve(path: &std::path::Path) -> std::path::PathBuf {
+    use std::path::{Component, PathBuf};
+    let mut resolved = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                resolved.pop();
+            }
+            component => {
+                resolved.push(component);
+                if let Ok(canonical) = resolved.canonicalize() {
+                    resolved = canonical;
+                }
+            }
+        }
+    }
+    resolved
+}
+/// Checks that `path` is within `root`, after resolving `..` components and symbolic links. Relative paths are
+/// resolved relative to `root`.
+///
+/// Every existing part of the path is canonicalized, so symbolic links pointing out of `root` are caught, even when
+/// they lead to a file which doesn't exist yet. The parts which don't exist yet (like a file about to be created) are
+/// only normalized, by removing their `.` and `..` components.
+#[cfg(feature = "std")]
+pub fn under<'a, P, R>(path: &'a P, root: &R) -> Result<(), OutsideRoot<'a>>
+where
+    P: AsRef<std::path::Path> + ?Sized,
+    R: AsRef<std::path::Path> + ?Sized,
+{
+    let (path, root) = (path.as_ref(), resolve(root.as_ref()));
+    let resolved = resolve(&root.join(path));
+    if resolved.starts_with(&root) {
+        Ok(())
+    } else {
+        Err(OutsideRoot { path, resolved, root })
+    }
+}
+/// Describes a value, which is not equal to the expected one.
+///
+/// With the `pretty` feature, it shows a [`Diff`] between their `Debug` representations, instead of printing both of
+/// them on a single line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotEqual<'a, T: ?Sized, U: ?Sized> {
+    /// The value.
+    pub actual: &'a T,
+    /// The expected value.
+    pub expected: &'a U,
+}
+impl<T: fmt::Debug + ?Sized, U: fmt::Debug + ?Sized> fmt::Display for NotEqual<'_, T, U> {
+    #[cfg(not(feature = "pretty"))]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "got {:?}, expected {:?}", self.actual, self.expected)
+    }
+    #[cfg(feature = "pretty")]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let expected = format!("{:#?}", self.expected);
+        let actual = format!("{:#?}", self.actual);
+        write!(f, "the values differ:\n{}", Diff { expected: &expected, actual: &actual })
+    }
+}
+/// Checks that `value` is equal to `expected`.
+pub fn eq<'a, T: PartialEq<U> + ?Sized, U: ?Sized>(value: &'a T, expected: &'a U) -> Result<(), NotEqual<'a, T, U>> {
+    if value == expected {
+        Ok(())
+    } else {
+        Err(NotEqual { actual: value, expected })
+    }
+}
+/// A colored, line-by-line diff between the `Debug` representations of an expected and an actual value.
+///
+/// Lines only in the expected value are prefixed with a red `-`, and lines only in the actual value with a green `+`.
+/// ```
+/// # use irrelevant::checks::Diff;
+/// let diff = Diff { expected: "[\n    1,\n    2,\n]", actual: "[\n    1,\n    3,\n]" };
+/// assert_eq!(
+///     diff.to_string(),
+///     "  [\n      1,\n\x1b[31m-     2,\x1b[0m\n\x1b[32m+     3,\x1b[0m\n  ]\n"
+/// );
+/// ```
+#[cfg(feature = "pretty")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Diff<'a> {
+    /// The representation of the expected value.
+    pub expected: &'a str,
+    /// The representation of the actual value.
+    pub actual: &'a str,
+}
+#[cfg(feature = "pretty")]
+impl fmt::Display for Diff<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let expected: Vec<&str> = self.expected.lines().collect();
+        let actual: Vec<&str> = self.actual.lines().collect();
+        // `common[i][j]` is the length of the longest common subsequence of `expected[i..]` and `actual[j..]`.
+        let mut common = vec![vec![0usize; actual.len() + 1]; expected.len() + 1];
+        for i in (0..expected.len()).rev() {
+            for j in (0..actual.len()).rev() {
+                common[i][j] = if expected[i] == actual[j] {
+                    common[i + 1][j + 1] + 1
+                } else {
+                    common[i + 1][j].max(common[i][j + 1])
+                };
+            }
+        }
+        let (mut i, mut j) = (0, 0);
+        while i < expected.len() || j < actual.len() {
+            if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+                writeln!(f, "  {}", expected[i])?;
+                i += 1;
+                j += 1;
+            } else if j == actual.len() || (i < expected.len() && common[i + 1][j] >= common[i][j + 1]) {
+                writeln!(f, "\x1b[31m- {}\x1b[0m", expected[i])?;
+                i += 1;
+            } else {
+                writeln!(f, "\x1b[32m+ {}\x1b[0m", actual[j])?;
+                j += 1;
+            }
+        }
+        Ok(())
+    }
+}
+/// Describes a range, which starts after its end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvertedRange<'a, T> {
+    /// The start of the range.
+    pub start: &'a T,
+    /// The end of the range.
+    pub end: &'a T,
+}
+impl<T: fmt::Debug> fmt::Display for InvertedRange<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the range starts at {:?}, after its end at {:?}", self.start, self.end)
+    }
+}
+/// Checks if a type-erased thread pool is idle, returning the number of active tasks if known.
+#[cfg(feature = "std")]
+type PoolProbe = Box<dyn Fn(&dyn Any) -> Result<(), Option<usize>> + Send>;
+#[cfg(feature = "std")]
+static POOLS: Mutex<Option<HashMap<TypeId, PoolProbe>>> = Mutex::new(None);
+/// Registers how to check if thread pools of type `P` are idle, for use with `idle`.
+///
+/// `is_idle` checks if the pool has no tasks running, and `active_tasks`, if the pool can tell it, returns the number
+/// of tasks running. Registering a pool type again replaces the previous registration.
+/// ```
+/// # use irrelevant::*;
+/// # use std::sync::atomic::{AtomicUsize, Ordering};
+/// struct Pool {
+///     active: AtomicUsize,
+/// }
+/// register_pool::<Pool>(|pool| pool.active.load(Ordering::Relaxed) == 0, Some(|pool| pool.active.load(Ordering::Relaxed)));
+/// let pool = Pool { active: AtomicUsize::new(0) };
+/// irrelevant!(pool, "All the jobs were joined before shutdown.", idle);
+/// ```
+#[cfg(feature = "std")]
+pub fn register_pool<P: Any>(is_idle: fn(&P) -> bool, active_tasks: Option<fn(&P) -> usize>) {
+    let probe: PoolProbe = Box::new(move |pool| {
+        let pool = pool.downcast_ref::<P>().expect("pools are registered by their type");
+        if is_idle(pool) {
+            Ok(())
+        } else {
+            Err(active_tasks.map(|active_tasks| active_tasks(pool)))
+        }
+    });
+    POOLS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get_or_insert_with(HashMap::new)
+        .insert(TypeId::of::<P>(), probe);
+}
+/// Describes a thread pool which is not idle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Busy {
+    /// The pool has tasks running, and knows how many, if `Some`.
+    Active(Option<usize>),
+    /// The type of the pool was never registered with [`register_pool`].
+    Unregistered(&'static str),
+}
+impl fmt::Display for Busy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Active(Some(active)) => write!(f, "{active} tasks are running"),
+            Self::Active(None) => write!(f, "tasks are running"),
+            Self::Unregistered(pool) => write!(f, "{pool} was never registered as a pool"),
+        }
+    }
+}
+/// Checks that `pool` is idle, using the checks registered for its type with [`register_pool`].
+#[cfg(feature = "std")]
+pub fn idle<P: Any>(pool: &P) -> Result<(), Busy> {
+    let pools = POOLS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    match pools.as_ref().and_then(|pools| pools.get(&TypeId::of::<P>())) {
+        Some(probe) => probe(pool).map_err(Busy::Active),
+        None => Err(Busy::Unregistered(core::any::type_name::<P>())),
+    }
+}
+/// Describes a value, which is not the default one, like a state machine which already left its initial state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotDefault<'a, T> {
+    /// The value.
+    pub actual: &'a T,
+    /// The default value of its type.
+    pub default: T,
+}
+impl<T: fmt::Debug> fmt::Display for NotDefault<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the variant is {}, not the default {}", Variant(self.actual), Variant(&self.default))
+    }
+}
+/// Displays the name of an enum variant, which is the start of its `Debug` representation, up to its fields.
+struct Variant<'a, T>(&'a T);
+impl<T: fmt::Debug> fmt::Display for Variant<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        /// Writes to the formatter until the fields of the variant start.
+        struct UntilFields<'a, 'b> {
+            f: &'a mut fmt::Formatter<'b>,
+            done: bool,
+        }
+        impl fmt::Write for UntilFields<'_, '_> {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                if self.done {
+                    return Ok(());
+                }
+                match s.find(['(', '{', ' ']) {
+                    Some(end) => {
+                        self.done = true;
+                        self.f.write_str(&s[..end])
+                    }
+                    None => self.f.write_str(s),
+                }
+            }
+        }
+        fmt::write(&mut UntilFields { f, done: false }, format_args!("{:?}", self.0))
+    }
+}
+/// Checks that `value` is equal to the default value of its type.
+pub fn is_default<T: Default + PartialEq>(value: &T) -> Result<(), NotDefault<'_, T>> {
+    let default = T::default();
+    if *value == default {
+        Ok(())
+    } else {
+        Err(NotDefault { actual: value, default })
+    }
+}
+/// A type with an invariant, which all of its values are expected to uphold.
+///
+/// Implementing it defines the invariant once, so it can be assumed with `invariant` wherever values of the type are
+/// ignored.
+/// ```
+/// # use irrelevant::*;
+/// # use irrelevant::checks::Invariant;
+/// struct Span {
+///     start: usize,
+///     end: usize,
+/// }
+/// impl Invariant for Span {
+///     fn holds(&self) -> bool {
+///         self.start <= self.end
+///     }
+/// }
+/// let span = Span { start: 1, end: 4 };
+/// irrelevant!(span, "Highlighting is not supported yet.", invariant);
+/// ```
+pub trait Invariant {
+    /// Returns `true` if the invariant holds for this value.
+    fn holds(&self) -> bool;
+}
+impl<T: Invariant + ?Sized> Invariant for &T {
+    fn holds(&self) -> bool {
+        (**self).holds()
+    }
+}
+/// Describes a value, which doesn't uphold the invariant of its type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BrokenInvariant {
+    /// The name of the type.
+    pub type_name: &'static str,
+}
+impl fmt::Display for BrokenInvariant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the invariant of {} does not hold", self.type_name)
+    }
+}
+/// Describes slices of floats, which are not elementwise close.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NotElementwiseClose {
+    /// The slices have different lengths.
+    Len {
+        /// The length of the slice.
+        len: usize,
+        /// The length of the expected slice.
+        expected: usize,
+    },
+    /// The first element, which is too far away from the expected one.
+    Element {
+        /// The index of the element.
+        index: usize,
+        /// The element.
+        value: f64,
+        /// The expected element.
+        expected: f64,
+        /// The tolerance.
+        tol: f64,
+    },
+}
+impl fmt::Display for NotElementwiseClose {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Len { len, expected } => write!(f, "{len} elements, expected {expected}"),
+            Self::Element { index, value, expected, tol } => {
+                write!(f, "the element at {index} is {value}, more than {tol} away from {expected}")
+            }
+        }
+    }
+}
+/// Checks that `values` have the same length as `expected`, and that each of them is at most `tol` away from the
+/// expected one. `NaN`s are never close to anything.
+pub fn elementwise_close<F, A, E>(values: &A, expected: &E, tol: f64) -> Result<(), NotElementwiseClose>
+where
+    F: Copy + Into<f64>,
+    A: AsRef<[F]> + ?Sized,
+    E: AsRef<[F]> + ?Sized,
+{
+    let (values, expected) = (values.as_ref(), expected.as_ref());
+    if values.len() != expected.len() {
+        return Err(NotElementwiseClose::Len { len: values.len(), expected: expected.len() });
+    }
+    for (index, (&value, &expected)) in values.iter().zip(expected).enumerate() {
+        let (value, expected) = (value.into(), expected.into());
+        let distance = if value < expected { expected - value } else { value - expected };
+        // Written so that `NaN`s fail the check, and infinities are only close to themselves.
+        if !(value == expected || distance <= tol) {
+            return Err(NotElementwiseClose::Element { index, value, expected, tol });
+        }
+    }
+    Ok(())
+}
+/// The most recent values ignored at each place in the source code, used by `within_p99`.
+#[cfg(feature = "std")]
+static RESERVOIRS: Mutex<Option<HashMap<crate::Location, VecDeque<f64>>>> = Mutex::new(None);
+/// The number of recent values kept for each place, so the percentiles follow slow changes in their distribution.
+#[cfg(feature = "std")]
+pub const RESERVOIR_CAPACITY: usize = 1000;
+/// The number of values which have to be ignored at a place before the percentiles are checked there.
+#[cfg(feature = "std")]
+pub const RESERVOIR_WARMUP: usize = 100;
+/// Describes a value above the 99th percentile of the previous ones.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AboveP99 {
+    /// The value.
+    pub value: f64,
+    /// The 99th percentile of the previous values.
+    pub p99: f64,
+}
+impl fmt::Display for AboveP99 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} is above the 99th percentile of the previous values, {}", self.value, self.p99)
+    }
+}
+/// Checks that `value` is at most the 99th percentile of the values previously ignored at `site`, and then keeps it
+/// with them.
+///
+/// Only the most recent [`RESERVOIR_CAPACITY`] values are kept for each place. Until [`RESERVOIR_WARMUP`] values were
+/// kept, there is too little history to tell, so the check always passes.
+#[cfg(feature = "std")]
+pub fn within_p99(site: crate::Location, value: impl Into<f64>) -> Result<(), AboveP99> {
+    let value = value.into();
+    let mut reservoirs = RESERVOIRS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let values = reservoirs.get_or_insert_with(HashMap::new).entry(site).or_default();
+    let outcome = if values.len() < RESERVOIR_WARMUP {
+        Ok(())
+    } else {
+        let mut sorted: Vec<f64> = values.iter().copied().collect();
+        sorted.sort_by(f64::total_cmp);
+        // The nearest-rank percentile: the smallest value, which at least 99% of the values are not above.
+        let p99 = sorted[(sorted.len() * 99).div_ceil(100) - 1];
+        if value <= p99 {
+            Ok(())
+        } else {
+            Err(AboveP99 { value, p99 })
+        }
+    };
+    if values.len() == RESERVOIR_CAPACITY {
+        values.pop_front();
+    }
+    values.push_back(value);
+    outcome
+}
+/// The first value of each type ignored at each place in the source code, used by `deterministic`.
+#[cfg(feature = "std")]
+static FIRST_VALUES: Mutex<Option<HashMap<(Location, TypeId), FirstValue>>> = Mutex::new(None);
+/// The first value of some type, ignored at some place.
+#[cfg(feature = "std")]
+type FirstValue = Box<dyn Any + Send>;
+/// Describes a value which differs from the first value of its type ignored at the same place.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Nondeterministic<'a, T> {
+    /// The value.
+    pub value: &'a T,
+    /// The first value.
+    pub first: T,
+}
+impl<T: fmt::Debug> fmt::Display for Nondeterministic<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} differs from the first value, {:?}", self.value, self.first)
+    }
+}
+/// Checks that `value` is equal to the first value of its type checked at `site`, or keeps a copy of it as the first
+/// value.
+///
+/// The first values are kept separately for each type, so generic code can be checked for every type it is used with.
+#[cfg(feature = "std")]
+pub fn deterministic<T>(site: Location, value: &T) -> Result<(), Nondeterministic<'_, T>>
+where
+    T: Any + Send + Clone + PartialEq,
+{
+    let mut first_values = FIRST_VALUES.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let first = first_values
+        .get_or_insert_with(HashMap::new)
+        .entry((site, TypeId::of::<T>()))
+        .or_insert_with(|| Box::new(value.clone()));
+    let first = first.downcast_ref::<T>().expect("the first values are keyed by their types");
+    if first == value {
+        Ok(())
+    } else {
+        Err(Nondeterministic { value, first: first.clone() })
+    }
+}
+/// The latest timestamp ignored with `after_last_global`, anywhere in the program.
+#[cfg(target_has_atomic = "64")]
+static LAST_TIMESTAMP: core::sync::atomic::AtomicU64 = core::sync::atomic::AtomicU64::new(0);
+/// Describes a timestamp before the latest one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockRegression {
+    /// The timestamp.
+    pub timestamp: u64,
+    /// The latest timestamp.
+    pub last: u64,
+}
+impl fmt::Display for ClockRegression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} is before the last timestamp, {}", self.timestamp, self.last)
+    }
+}
+/// Checks that `timestamp` is not before the latest timestamp checked by `after_last_global`, and makes it the latest
+/// one if it is after it.
+///
+/// The latest timestamp is shared by the whole program, so timestamps taken from the same clock at different places
+/// are checked against each other. Equal timestamps are accepted, since clocks have a limited resolution. A timestamp
+/// before the latest one is not kept, so a single regression doesn't cause violations for the timestamps after it.
+#[cfg(target_has_atomic = "64")]
+pub fn after_last_global(timestamp: u64) -> Result<(), ClockRegression> {
+    use core::sync::atomic::Ordering;
+    let mut current = LAST_TIMESTAMP.load(Ordering::Relaxed);
+    loop {
+        if timestamp < current {
+            return Err(ClockRegression { timestamp, last: current });
+        }
+        if timestamp == current {
+            return Ok(());
+        }
+        match LAST_TIMESTAMP.compare_exchange_weak(current, timestamp, Ordering::Relaxed, Ordering::Relaxed) {
+            Ok(_) => return Ok(()),
+            Err(actual) => current = actual,
+        }
+    }
+}
+/// Describes a forbidden byte found in a byte buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ForbiddenByte {
+    /// The index of the first forbidden byte.
+    pub index: usize,
+    /// The forbidden byte.
+    pub byte: u8,
+}
+impl fmt::Display for ForbiddenByte {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "found the byte {:#04x} at {}", self.byte, self.index)
+    }
+}
+/// Checks that `bytes` don't contain `byte`.
+pub fn no_byte<B: AsRef<[u8]> + ?Sized>(bytes: &B, byte: u8) -> Result<(), ForbiddenByte> {
+    match bytes.as_ref().iter().position(|&b| b == byte) {
+        Some(index) => Err(ForbiddenByte { index, byte }),
+        None => Ok(()),
+    }
+}
+/// The results of validating recently ignored strings with a validator. The most recently used result is at the back.
+#[cfg(feature = "validation-cache")]
+type ValidationCache = VecDeque<(String, bool)>;
+/// The caches of the validators used by `cached_valid`, keyed by their addresses.
+#[cfg(feature = "validation-cache")]
+static VALIDATION_CACHES: Mutex<Option<HashMap<usize, ValidationCache>>> = Mutex::new(None);
+/// The number of results cached for each validator, with the `validation-cache` feature. The least recently used
+/// result is evicted first.
+#[cfg(feature = "validation-cache")]
+pub const VALIDATION_CACHE_CAPACITY: usize = 32;
+/// Returns the result of validating `value`, only calling `validator` if it isn't cached.
+#[cfg(feature = "validation-cache")]
+fn validate(validator: fn(&str) -> bool, value: &str) -> bool {
+    // Validators are identified by their address, so all the places using one share its results.
+    let key = validator as usize;
+    {
+        let mut caches = VALIDATION_CACHES.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let results = caches.get_or_insert_with(HashMap::new).entry(key).or_default();
+        if let Some(pos) = results.iter().position(|(cached, _)| cached == value) {
+            let result = results.remove(pos).expect("the position is in bounds");
+            let valid = result.1;
+            results.push_back(result);
+            return valid;
+        }
+    }
+    // The validator may be slow, or ignore values itself, so it runs without holding the lock.
+    let valid = validator(value);
+    let mut caches = VALIDATION_CACHES.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let results = caches.get_or_insert_with(HashMap::new).entry(key).or_default();
+    // Another thread may have validated the same string in the meantime.
+    if !results.iter().any(|(cached, _)| cached == value) {
+        if results.len() == VALIDATION_CACHE_CAPACITY {
+            results.pop_front();
+        }
+        results.push_back((value.to_owned(), valid));
+    }
+    valid
+}
+#[cfg(not(feature = "validation-cache"))]
+fn validate(validator: fn(&str) -> bool, value: &str) -> bool {
+    validator(value)
+}
+/// Describes a string rejected by a validator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Invalid<'a> {
+    /// The string.
+    pub value: &'a str,
+}
+impl fmt::Display for Invalid<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} was rejected by the validator", self.value)
+    }
+}
+/// Checks that `value` is accepted by `validator`, reusing the cached result, if there is one.
+///
+/// Results are only cached with the `validation-cache` feature. They are shared by all the places using the same
+/// validator.
+pub fn cached_valid<'a, S: AsRef<str> + ?Sized>(validator: fn(&str) -> bool, value: &'a S) -> Result<(), Invalid<'a>> {
+    let value = value.as_ref();
+    if validate(validator, value) {
+        Ok(())
+    } else {
+        Err(Invalid { value })
+    }
+}
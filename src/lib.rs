@@ -1,9 +1,145 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 //! This crate contains macros for ignoring variables in a more explicit fashion.
 //! It allows you to specify *why* a variable is ignored, and enforce certain assumptions about its value or type.
 //! It also prevents you from accidentally using an ingored variable by automatically shadowing it.
+//! # Features
+//! * `std` (default) - support for types from the standard library, and printing violated assumptions to `stderr`.
+//!   Without it, the crate is `no_std`, and violated assumptions are only reported to the handler set with
+//!   [`set_violation_handler`].
+//! * `alloc` - [`ViolationRecord`]s, which own their reasons as `String`s. Enabled by `std`.
+//! * `checks` (default) - checks the assumptions. Without it, the checks are compiled out, so their conditions are
+//!   never evaluated, and only the shadowing of the ignored values remains. Unlike [`debug_irrelevant`], this applies
+//!   to all the variants of the macro, in all build profiles. The conditions are still type-checked when building, so
+//!   the code only used by them doesn't become unused.
+//! * `build-script` - logs violated assumptions as `cargo:warning=` lines on `stdout`, so they show up in cargo's output
+//!   when the macros are used in a build script.
+//! * `snapshot` - the `snapshot "path"` assumption, comparing values against snapshots stored in files.
+//! * `pretty` - shows colored diffs between the expected and actual values, when an equality assumption is violated.
+//! * `validation-cache` - caches the results of the validators used with the `cached_valid` assumption.
+//! * `max-reason-len` - asserts at compile time that the reasons of checked assumptions are at most 128 bytes long, so
+//!   the lines reporting their violations stay bounded. The limit can be changed with the `IRRELEVANT_MAX_REASON_LEN`
+//!   environment variable, when building.
+//! * `alloc-counter` - the [`CountingAllocator`], a global allocator counting allocations, for the
+//!   `no_alloc_since(marker)` assumption.
+//! * `attributes` - the [`irrelevant_params`] attribute, ignoring function parameters marked with `#[irrelevant]`.
+//! * `backtrace` - captures a backtrace when an assumption is violated, and prints it after the message. Violation
+//!   handlers get it as the `backtrace` field of [`ViolationInfo`]. Backtraces are only captured when enabled with the
+//!   `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment variables. Violations in [`panic_irrelevant`] don't need this
+//!   feature, since the panic hook prints the backtrace of the panic.
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod checks;
+/// Ignores the parameters of a function marked with `#[irrelevant]`, just like [`irrelevant`] at the top of its body
+/// would. Requires the `attributes` feature.
+/// ```
+/// # use irrelevant::*;
+/// # struct Context;
+/// #[irrelevant_params]
+/// fn add(a: u32, b: u32, #[irrelevant("Adding numbers does not require any privileges.")] context: &Context) -> u32 {
+///     a + b
+/// }
+/// #[irrelevant_params]
+/// fn greet(name: &str, #[irrelevant(reason = "Greetings are not localized yet.", assume = is_empty)] locale: &str) {
+///     println!("Hello, {name}!");
+/// }
+/// ```
+/// The ignored parameters are shadowed, so they can't be used by accident.
+/// ```compile_fail
+/// # use irrelevant::*;
+/// # struct Context { admin: bool }
+/// #[irrelevant_params]
+/// fn add(a: u32, b: u32, #[irrelevant("Adding numbers does not require any privileges.")] context: &Context) -> u32 {
+///     // `context` has been ignored, so this variable can't be used here!
+///     assert!(!context.admin);
+///     a + b
+/// }
+/// ```
+/// The generated code refers to this crate as `::irrelevant`. If it is renamed, or only reachable through a re-export,
+/// pass its path with `crate = path`.
+/// ```
+/// # extern crate irrelevant as ignoring;
+/// # use ignoring::*;
+/// # struct Context;
+/// #[irrelevant_params(crate = ignoring)]
+/// fn add(a: u32, b: u32, #[irrelevant("Adding numbers does not require any privileges.")] context: &Context) -> u32 {
+///     a + b
+/// }
+/// ```
+#[cfg(feature = "attributes")]
+pub use irrelevant_macros::irrelevant_params;
+#[cfg(feature = "std")]
+pub use checks::{register_pool, register_validator};
+#[cfg(feature = "alloc-counter")]
+mod alloc_counter;
+#[cfg(feature = "std")]
+mod log_file;
+mod must_ignore;
+#[cfg(feature = "std")]
+mod recursion;
+mod reason_len;
+mod scoped;
+mod take;
+#[cfg(feature = "std")]
+mod temporary;
+mod todo;
+mod try_irrelevant;
+mod violation;
+#[cfg(feature = "alloc-counter")]
+pub use alloc_counter::{AllocMarker, CountingAllocator};
+#[cfg(feature = "std")]
+pub use log_file::{clear_violation_file, set_violation_file, set_violation_sampling};
+#[cfg(feature = "std")]
+pub use recursion::RecursionGuard;
+pub use must_ignore::MustIgnore;
+pub use scoped::ScopedIgnore;
+pub use try_irrelevant::IgnoreViolation;
+#[doc(hidden)]
+pub use take::__assert_not_copy;
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub use temporary::__register_temporary;
+#[cfg(feature = "std")]
+pub use temporary::temporary_ignores;
+#[cfg(feature = "max-reason-len")]
+#[doc(hidden)]
+pub use reason_len::__MAX_REASON_LEN;
+#[doc(hidden)]
+pub use violation::{__panic_violation, __record_violation, __report_violation};
+#[cfg(feature = "std")]
+pub use violation::{capture_violations, last_violation, set_abort_on_error};
+pub use violation::{reset_violation_count, set_violation_handler, violation_count, Severity, ViolationInfo};
+#[cfg(feature = "alloc")]
+pub use violation::ViolationRecord;
 
 /// This marker signifies that a value has been explicitly ignored.
+///
+/// Ignored values are shadowed with it. It implements the common traits, so the shadowed binding can still be stored,
+/// compared or printed, without giving access to the ignored value.
+/// ```
+/// # use irrelevant::*;
+/// let sauces = ["ketchup"];
+/// irrelevant!(sauces, "Sauces are served separately.");
+/// assert_eq!(sauces.to_string(), "<explicitly ignored>");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 pub struct ExplicitlyIgnoredValue;
+impl core::fmt::Display for ExplicitlyIgnoredValue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("<explicitly ignored>")
+    }
+}
+/// A place in the source code where a value is ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Location {
+    /// The file, as returned by [`file`].
+    pub file: &'static str,
+    /// The line, as returned by [`line`].
+    pub line: u32,
+    /// The column, as returned by [`column`].
+    pub column: u32,
+}
 /// This macro allows you to explicitly ignore a value, provide a reason for ignoring it, and automatically check your assumptions.
 ///
 /// WARNING: this macro runs checks in both debug and release mode. For debug-only checks, use [`debug_irrelevant`].
@@ -59,11 +195,12 @@ pub struct ExplicitlyIgnoredValue;
 ///             return CocoaMilkSubstitite::new(exclude_allergens,amount,sauces);
 ///         }
 ///         irrelevant!(sauces,"No sauces should come with a drink!",sauces.is_empty());
-///         return Box::new(Self{ammount});
+///         return Box::new(Self{ammount:amount});
 ///     }
 /// }
 /// ```
-/// When this assumption is violated, an error message will be printed to `stderr`.
+/// When this assumption is violated, an error message will be printed to `stderr`, unless another handler is set with
+/// [`set_violation_handler`].
 /// For this example, the message will look like this:
 /// ```text
 /// [src/main.rs:65:10] Assumption violated: No sauces should come with a drink!
@@ -87,10 +224,88 @@ pub struct ExplicitlyIgnoredValue;
 /// #            return CocoaMilkSubstitite::new(exclude_allergens,amount,sauces);
 /// #       }
 /// irrelevant!(sauces,"No sauces should come with a drink!",is_empty);
-/// #       return Box::new(Self{ammount});
+/// #       return Box::new(Self{ammount:amount});
 /// #   }
 /// # }
 /// ```
+/// Conditions are always checked before the value is shadowed, so they can refer to the ignored value itself, and to
+/// its fields.
+/// ```
+/// # use irrelevant::*;
+/// # struct Range{start:u32,end:u32}
+/// # let range = Range{start:0,end:0};
+/// irrelevant!(range, "Empty ranges are skipped.", range.start == range.end);
+/// ```
+/// If checking an assumption is expensive, you can guard it with a cheaper check. The expensive check only runs when
+/// the cheap one fails.
+/// ```
+/// # use irrelevant::*;
+/// # let words = ["apple", "banana"];
+/// irrelevant!(words, "The words are sorted by the caller.", words.len() < 2 => words.windows(2).all(|w| w[0] <= w[1]));
+/// ```
+/// Several conditions can be checked at once, by listing them in brackets. They are all checked before the value is
+/// shadowed, and the violations report the index of the condition which doesn't hold, counting from 0.
+/// ```
+/// # use irrelevant::*;
+/// # let buffer: Vec<u8> = Vec::new();
+/// irrelevant!(buffer, "Buffers are only allocated when written to.", [buffer.is_empty(), buffer.capacity() == 0]);
+/// ```
+/// Values can also be compared against bounds, using `<`, `<=`, `>` or `>=` in place of the condition.
+/// ```
+/// # use irrelevant::*;
+/// # const MAX_DEPTH: usize = 64;
+/// # let depth = 3;
+/// irrelevant!(depth, "The tree is balanced, so it is never too deep.", <= MAX_DEPTH);
+/// ```
+/// Or against a range, using `in`, with any kind of range, like `a..b`, `a..=b` or `..=b`.
+/// ```
+/// # use irrelevant::*;
+/// # let (index, len) = (0, 4);
+/// irrelevant!(index, "The index is only used for bounds checks, and is always in bounds.", in 0..len);
+/// ```
+/// Or against a known value, using `==`. When they differ, both the value and the expected one are reported, so they
+/// need to implement `Debug`.
+/// ```
+/// # use irrelevant::*;
+/// # #[derive(Debug, PartialEq)]
+/// # enum Status{Ready}
+/// # let status = Status::Ready;
+/// irrelevant!(status, "Requests are only handled once the server is ready.", == Status::Ready);
+/// ```
+/// To see the value when a condition doesn't hold, prefix the condition with `dbg`. The `Debug` representation of the
+/// value is then appended to the message, like `(value = 3)`, after the details of the violation if there are any. This
+/// is opt-in, so other conditions don't require the value to implement `Debug`.
+/// ```
+/// # use irrelevant::*;
+/// # let ice = 0;
+/// irrelevant!(ice, "Hot drinks come without ice.", dbg ice == 0);
+/// ```
+/// ```compile_fail
+/// # use irrelevant::*;
+/// struct Ice;
+/// let ice = Ice;
+/// irrelevant!(ice, "Hot drinks come without ice.", dbg matches!(ice, Ice));
+/// ```
+/// The reason can also include format arguments, separated from the condition with a `;`. They are only formatted
+/// when the assumption is violated, so they cost nothing when it holds.
+/// ```
+/// # use irrelevant::*;
+/// # let sauces = [(); 0];
+/// irrelevant!(sauces, "Expected no sauces, but got {}.", sauces.len(); sauces.is_empty());
+/// ```
+/// Violated assumptions can also be reported with a severity, by starting with `info:`, `warn:` or `error:`. The
+/// severity is passed to the violation handler, and prefixes the message printed to `stderr`. Violations with the
+/// `error` severity can also abort the process, see [`set_abort_on_error`].
+/// ```
+/// # use irrelevant::*;
+/// # let (sauces, ice) = ([(); 0], 0);
+/// irrelevant!(info: sauces, "No sauces should come with a drink!", is_empty);
+/// irrelevant!(error: ice, "Hot drinks come without ice.", == 0);
+/// ```
+/// The message for a violation with a severity looks like this:
+/// ```text
+/// error: [src/main.rs:65:10] Assumption violated: Hot drinks come without ice. (got 2, expected 0)
+/// ```
 /// If you want to panic on a violated assumption, use [`panic_irrelevant`].
 /// # Ignoring without checks
 /// You can also ignore a value without any checks.
@@ -106,6 +321,102 @@ pub struct ExplicitlyIgnoredValue;
 /// //probably should have a reason for ignoring this value...
 /// irrelevant!(val);
 /// ```
+/// # Named assumptions
+/// Some assumptions come up often enough to have a name of their own. Those are written in place of the condition,
+/// and are documented in the [`checks`] module, together with the traits and helpers backing them.
+/// ```
+/// # use irrelevant::*;
+/// # use std::sync::{mpsc, Arc};
+/// let (tx, _rx) = mpsc::channel::<()>();
+/// let tx = Arc::new(tx);
+/// irrelevant!(tx, "This is the last sender, so dropping it closes the channel.", last_sender);
+/// ```
+/// # Ignoring several values
+/// Several values can be ignored at once, for the same reason, by listing them in square brackets. Each of them can
+/// also be followed by its own condition, checked before any of them are shadowed.
+/// ```
+/// # use irrelevant::*;
+/// # let (sauces, ice, straw) = ([(); 0], 0, true);
+/// irrelevant!([sauces => sauces.is_empty(), ice => ice == 0, straw], "Hot drinks come plain.");
+/// ```
+/// Every listed value is shadowed.
+/// ```compile_fail
+/// # use irrelevant::*;
+/// # let (sauces, ice, straw) = ([(); 0], 0, true);
+/// irrelevant!([sauces, ice, straw], "Hot drinks come plain.");
+/// // `ice` has been ignored, so this variable can't be used here!
+/// println!("{}", ice + 1);
+/// ```
+/// # Ignoring expressions
+/// Besides variables, any expression can be ignored, like a field or the result of a function call. Expressions are not
+/// variables, so they can't be shadowed. Instead, when there is an assumption, the value of the expression is moved
+/// into a temporary, which the assumption is checked on, and which is dropped right after. Named assumptions, and
+/// conditions like `is_empty`, apply to that temporary. To ignore a field without moving it out, borrow it.
+/// ```
+/// # use irrelevant::*;
+/// # struct Cache{entries:Vec<u32>}
+/// # struct Renderer{cache:Cache,depth:usize}
+/// # impl Renderer{ fn frame(&self)->u32{0} }
+/// # let renderer = Renderer{cache:Cache{entries:vec![]},depth:0};
+/// irrelevant!(renderer.depth, "Frames are rendered flat.", <= 1);
+/// irrelevant!(&renderer.cache.entries, "Nothing is cached before the first frame.", is_empty);
+/// irrelevant!(renderer.frame(), "The first frame is always rendered.");
+/// ```
+/// # Ignoring the receiver
+/// The receiver of a method, `self`, can be ignored too, with all the kinds of assumptions. It can't be shadowed, so
+/// unlike other values, it can still be used afterwards.
+/// ```
+/// # use irrelevant::*;
+/// struct Metrics {
+///     samples: Vec<u32>,
+/// }
+/// impl Metrics {
+///     fn name(&self) -> &'static str {
+///         irrelevant!(self, "All the metrics share a name until sampling is implemented.", self.samples.is_empty());
+///         "metrics"
+///     }
+/// }
+/// ```
+/// Fields (like `self.note`) and dereferenced values (like `*boxed`) are checked where they are, without being moved
+/// out, so they can be ignored even if they are only borrowed. Just like `self`, they can't be shadowed.
+/// ```
+/// # use irrelevant::*;
+/// struct Order {
+///     note: String,
+///     ice: Box<u32>,
+/// }
+/// fn serve(order: &mut Order) {
+///     irrelevant!(order.note, "Notes are only read by the cook.", is_empty);
+///     irrelevant!(order.ice, "Hot drinks come without ice.", *order.ice == 0);
+/// }
+/// # serve(&mut Order { note: String::new(), ice: Box::new(0) });
+/// ```
+/// Several fields of a struct can be ignored at once, by destructuring it. The fields are bound and then shadowed,
+/// without being moved out of the struct. The assumption, if any, is checked about the whole struct first.
+/// ```
+/// # use irrelevant::*;
+/// struct Config {
+///     timeout: u64,
+///     retries: u32,
+///     endpoint: String,
+/// }
+/// fn connect(config: &Config) -> &str {
+///     irrelevant!(Config { timeout, retries, .. } = config, "Tuning is done by the pool.", config.retries < 10);
+///     &config.endpoint
+/// }
+/// # connect(&Config { timeout: 30, retries: 3, endpoint: String::new() });
+/// ```
+/// ```compile_fail
+/// # use irrelevant::*;
+/// # struct Config {
+/// #     timeout: u64,
+/// #     retries: u32,
+/// # }
+/// # let config = Config { timeout: 30, retries: 3 };
+/// irrelevant!(Config { timeout, retries } = config, "Tuning is done by the pool.");
+/// // `timeout` has been ignored, so this variable can't be used here!
+/// let deadline: u64 = timeout * 2;
+/// ```
 /// # Additional features
 /// This macro also always automatically shadows the value, preventing you from using it accidentally.
 /// ```compile_fail
@@ -119,151 +430,931 @@ pub struct ExplicitlyIgnoredValue;
 /// ```
 #[macro_export]
 macro_rules! irrelevant {
+    // A value is ignored with a severity, reported when an assumption about it is violated.
+    (info: $($ignore:tt)+) => {
+        $crate::__irrelevant_severity!(info, $($ignore)+);
+    };
+    (warn: $($ignore:tt)+) => {
+        $crate::__irrelevant_severity!(warning, $($ignore)+);
+    };
+    (error: $($ignore:tt)+) => {
+        $crate::__irrelevant_severity!(error, $($ignore)+);
+    };
+    // A value wrapped in a `MustIgnore` is unwrapped, and then ignored.
+    (must $val:ident $(,$reason:literal $(,$($assumption:tt)+)?)?) => {
+        let $val = $crate::MustIgnore::into_inner($val);
+        $crate::irrelevant!($val $(,$reason $(,$($assumption)+)?)?);
+    };
+    (must $val:expr $(,$reason:literal $(,$($assumption:tt)+)?)?) => {{
+        let value = $crate::MustIgnore::into_inner($val);
+        $crate::irrelevant!(value $(,$reason $(,$($assumption)+)?)?);
+    }};
     // A value is ignored without any given reason.
     ($val:ident) => {
-        let _ = $val;
-        let $val = irrelevant::ExplicitlyIgnoredValue;
-        let _ = $val;
+        $crate::__irrelevant_shadow!($val);
     };
     // A value is ignored without any additional assumption.
     ($val:ident,$reason:literal) => {
         //$reason
-        let _ = $val;
-        let $val = irrelevant::ExplicitlyIgnoredValue;
-        let _ = $val;
+        $crate::__irrelevant_shadow!($val);
     };
     // A value is ignored because of an assumption.
-    ($val:ident,$reason:literal,$cond:ident) => {
-        if !($val.$cond()) {
-            let file = file!();
-            let line = line!();
-            let column = column!();
-            eprintln!("[{file}:{line}:{column}] Assumption violated: {}", $reason)
-        }
-
-        let _ = $val;
-        let $val = irrelevant::ExplicitlyIgnoredValue;
-        let _ = $val;
+    ($val:ident,$reason:literal,$($assumption:tt)+) => {
+        $crate::__irrelevant_reason_args!(warn, $val, $reason, [] $($assumption)+);
     };
-    // A value is ignored because its type is not relevant.
-    ($val:ident,$reason:literal,$tpe:ty) => {
-        let _: $tpe = $val;
-        let $val = irrelevant::ExplicitlyIgnoredValue;
-        let _ = $val;
+    // Several values are ignored at once, optionally because of assumptions about some of them.
+    ([$($val:ident $(=> $cond:expr)?),+ $(,)?],$reason:literal) => {
+        $($($crate::__irrelevant_check!(warn, $reason, if $cond);)?)+
+        $($crate::__irrelevant_shadow!($val);)+
     };
-    // A value is ignored because of an assumption.
-    ($val:ident,$reason:literal,$cond:expr) => {
-        if !($cond) {
-            let file = file!();
-            let line = line!();
-            let column = column!();
-            eprintln!("[{file}:{line}:{column}] Assumption violated: {}", $reason)
-        }
-
+    // Coordinates are ignored because of an assumption.
+    ([$($val:ident),+ $(,)?],$reason:literal,$($assumption:tt)+) => {
+        $crate::__irrelevant_assume!(warn, [$($val),+], $reason, $($assumption)+);
+    };
+    // Fields of a struct are ignored, by destructuring it. They are checked together, as a part of the struct.
+    ($($struct:ident)::+ { $($pattern:tt)+ } = $val:expr,$reason:literal $(,$($assumption:tt)+)?) => {
+        $crate::__irrelevant_fields!(warn, $($struct)::+ { $($pattern)+ }, $val, $reason $(,$($assumption)+)?);
+    };
+    // An expression is ignored. It is not a variable, so it can't be shadowed, and is only evaluated.
+    ($val:expr) => {
         let _ = $val;
-        let $val = irrelevant::ExplicitlyIgnoredValue;
+    };
+    ($val:expr,$reason:literal) => {
+        //$reason
         let _ = $val;
     };
+    // A place, like a field of a struct, is ignored because of an assumption. It is checked where it is, so it is never
+    // moved out, which would fail if it is only borrowed.
+    ($base:ident $(.$field:tt)+,$reason:literal,$($assumption:tt)+) => {
+        $crate::__irrelevant_reason_args!(warn, ($base $(.$field)+), $reason, [] $($assumption)+);
+    };
+    (*$val:ident,$reason:literal,$($assumption:tt)+) => {
+        $crate::__irrelevant_reason_args!(warn, (*$val), $reason, [] $($assumption)+);
+    };
+    // An expression is ignored because of an assumption. Its value is moved into a temporary, which is checked and
+    // then dropped.
+    ($val:expr,$reason:literal,$($assumption:tt)+) => {{
+        let value = $val;
+        $crate::__irrelevant_reason_args!(warn, value, $reason, [] $($assumption)+);
+    }};
 }
 /// A version of [`irrelevant`] that panics when an assumption is violated. Besides that, it behaves exactly like [`irrelevant`].  
 #[macro_export]
 macro_rules! panic_irrelevant {
     // A value is ignored without any given reason.
     ($val:ident) => {
-        let _ = $val;
-        let $val = irrelevant::ExplicitlyIgnoredValue;
-        let _ = $val;
+        $crate::__irrelevant_shadow!($val);
     };
     // A value is ignored without any additional assumption.
     ($val:ident,$reason:literal) => {
         //$reason
-        let _ = $val;
-        let $val = irrelevant::ExplicitlyIgnoredValue;
-        let _ = $val;
+        $crate::__irrelevant_shadow!($val);
     };
     // A value is ignored because of an assumption.
-    ($val:ident,$reason:literal,$cond:ident) => {
-        if !($val.$cond()) {
-            let file = file!();
-            let line = line!();
-            let column = column!();
-            panic!("[{file}:{line}:{column}] Assumption violated: {}", $reason)
-        }
-
-        let _ = $val;
-        let $val = irrelevant::ExplicitlyIgnoredValue;
-        let _ = $val;
+    ($val:ident,$reason:literal,$($assumption:tt)+) => {
+        $crate::__irrelevant_reason_args!(panic, $val, $reason, [] $($assumption)+);
     };
-    // A value is ignored because its type is not relevant.
-    ($val:ident,$reason:literal,$tpe:ty) => {
-        let _: $tpe = $val;
-        let $val = irrelevant::ExplicitlyIgnoredValue;
-        let _ = $val;
+    // Several values are ignored at once, optionally because of assumptions about some of them.
+    ([$($val:ident $(=> $cond:expr)?),+ $(,)?],$reason:literal) => {
+        $($($crate::__irrelevant_check!(panic, $reason, if $cond);)?)+
+        $($crate::__irrelevant_shadow!($val);)+
     };
-    // A value is ignored because of an assumption.
-    ($val:ident,$reason:literal,$cond:expr) => {
-        if !($cond) {
-            let file = file!();
-            let line = line!();
-            let column = column!();
-            panic!("[{file}:{line}:{column}] Assumption violated: {}", $reason)
-        }
-
+    // Coordinates are ignored because of an assumption.
+    ([$($val:ident),+ $(,)?],$reason:literal,$($assumption:tt)+) => {
+        $crate::__irrelevant_assume!(panic, [$($val),+], $reason, $($assumption)+);
+    };
+    // Fields of a struct are ignored, by destructuring it. They are checked together, as a part of the struct.
+    ($($struct:ident)::+ { $($pattern:tt)+ } = $val:expr,$reason:literal $(,$($assumption:tt)+)?) => {
+        $crate::__irrelevant_fields!(panic, $($struct)::+ { $($pattern)+ }, $val, $reason $(,$($assumption)+)?);
+    };
+    // An expression is ignored. It is not a variable, so it can't be shadowed, and is only evaluated.
+    ($val:expr) => {
         let _ = $val;
-        let $val = irrelevant::ExplicitlyIgnoredValue;
+    };
+    ($val:expr,$reason:literal) => {
+        //$reason
         let _ = $val;
     };
+    // A place, like a field of a struct, is ignored because of an assumption. It is checked where it is, so it is never
+    // moved out, which would fail if it is only borrowed.
+    ($base:ident $(.$field:tt)+,$reason:literal,$($assumption:tt)+) => {
+        $crate::__irrelevant_reason_args!(panic, ($base $(.$field)+), $reason, [] $($assumption)+);
+    };
+    (*$val:ident,$reason:literal,$($assumption:tt)+) => {
+        $crate::__irrelevant_reason_args!(panic, (*$val), $reason, [] $($assumption)+);
+    };
+    // An expression is ignored because of an assumption. Its value is moved into a temporary, which is checked and
+    // then dropped.
+    ($val:expr,$reason:literal,$($assumption:tt)+) => {{
+        let value = $val;
+        $crate::__irrelevant_reason_args!(panic, value, $reason, [] $($assumption)+);
+    }};
 }
 /// A version of [`irrelevant`] that only runs checks in debug mode. Besides that, it behaves exactly like [`irrelevant`].  
 #[macro_export]
 macro_rules! debug_irrelevant {
     // A value is ignored without any given reason.
     ($val:ident) => {
-        let _ = $val;
-        let $val = irrelevant::ExplicitlyIgnoredValue;
-        let _ = $val;
+        $crate::__irrelevant_shadow!($val);
     };
     // A value is ignored without any additional assumption.
     ($val:ident,$reason:literal) => {
         //$reason
+        $crate::__irrelevant_shadow!($val);
+    };
+    // A value is ignored because of an assumption.
+    ($val:ident,$reason:literal,$($assumption:tt)+) => {
+        $crate::__irrelevant_reason_args!(debug, $val, $reason, [] $($assumption)+);
+    };
+    // Several values are ignored at once, optionally because of assumptions about some of them.
+    ([$($val:ident $(=> $cond:expr)?),+ $(,)?],$reason:literal) => {
+        $($($crate::__irrelevant_check!(debug, $reason, if $cond);)?)+
+        $($crate::__irrelevant_shadow!($val);)+
+    };
+    // Coordinates are ignored because of an assumption.
+    ([$($val:ident),+ $(,)?],$reason:literal,$($assumption:tt)+) => {
+        $crate::__irrelevant_assume!(debug, [$($val),+], $reason, $($assumption)+);
+    };
+    // Fields of a struct are ignored, by destructuring it. They are checked together, as a part of the struct.
+    ($($struct:ident)::+ { $($pattern:tt)+ } = $val:expr,$reason:literal $(,$($assumption:tt)+)?) => {
+        $crate::__irrelevant_fields!(debug, $($struct)::+ { $($pattern)+ }, $val, $reason $(,$($assumption)+)?);
+    };
+    // An expression is ignored. It is not a variable, so it can't be shadowed, and is only evaluated.
+    ($val:expr) => {
         let _ = $val;
-        let $val = irrelevant::ExplicitlyIgnoredValue;
+    };
+    ($val:expr,$reason:literal) => {
+        //$reason
         let _ = $val;
     };
-    // A value is ignored because of an assumption.
-    ($val:ident,$reason:literal,$cond:ident) => {
-        #[cfg(debug_assertions)]
-        {
-            if $val.$cond() {
-                let file = file!();
-                let line = line!();
-                let column = column!();
-                eprintln!("[{file}:{line}:{column}] Assumption violated:{}", $reason)
-            }
-        }
+    // A place, like a field of a struct, is ignored because of an assumption. It is checked where it is, so it is never
+    // moved out, which would fail if it is only borrowed.
+    ($base:ident $(.$field:tt)+,$reason:literal,$($assumption:tt)+) => {
+        $crate::__irrelevant_reason_args!(debug, ($base $(.$field)+), $reason, [] $($assumption)+);
+    };
+    (*$val:ident,$reason:literal,$($assumption:tt)+) => {
+        $crate::__irrelevant_reason_args!(debug, (*$val), $reason, [] $($assumption)+);
+    };
+    // An expression is ignored because of an assumption. Its value is moved into a temporary, which is checked and
+    // then dropped.
+    ($val:expr,$reason:literal,$($assumption:tt)+) => {{
+        let value = $val;
+        $crate::__irrelevant_reason_args!(debug, value, $reason, [] $($assumption)+);
+    }};
+}
+/// Ignores multiple values at once, each with its own reason. Every value is shadowed, just like with [`irrelevant`].
+/// ```
+/// # use irrelevant::*;
+/// fn handle(request: &str, session: u64, locale: &str, trace_id: u128) -> usize {
+///     irrelevant_chain!(
+///         session => "Anonymous requests are also handled.",
+///         locale => "The response is not localized.",
+///         trace_id => "Tracing is done by the proxy.",
+///     );
+///     request.len()
+/// }
+/// ```
+/// ```compile_fail
+/// # use irrelevant::*;
+/// # let (session, locale) = (0, "");
+/// irrelevant_chain!(session => "Anonymous requests are also handled.", locale => "The response is not localized.");
+/// // `locale` has been ignored, so this variable can't be used here!
+/// println!("{}", locale.len());
+/// ```
+#[macro_export]
+macro_rules! irrelevant_chain {
+    ($($val:ident => $reason:literal),+ $(,)?) => {
+        $($crate::irrelevant!($val, $reason);)+
+    };
+}
+/// A version of [`irrelevant`] for values ignored because of their type, which also asserts the size of the type at
+/// compile time.
+///
+/// A value of a zero-sized type, or of a small one, may be irrelevant only because of what it can (or can't) store.
+/// Asserting its size catches layout changes which make the value relevant again when building, not at runtime.
+/// The assertion is a constant item, so the type can't depend on the generic parameters of the surrounding function.
+/// ```
+/// # use irrelevant::*;
+/// struct Unprivileged;
+/// fn add(a: u32, b: u32, permissions: Unprivileged) -> u32 {
+///     irrelevant_static!(permissions, "Unprivileged permissions carry no data.", type = Unprivileged, size = 0);
+///     a + b
+/// }
+/// ```
+/// ```compile_fail
+/// # use irrelevant::*;
+/// struct Unprivileged {
+///     user: u32,
+/// }
+/// fn add(a: u32, b: u32, permissions: Unprivileged) -> u32 {
+///     irrelevant_static!(permissions, "Unprivileged permissions carry no data.", type = Unprivileged, size = 0);
+///     a + b
+/// }
+/// ```
+#[macro_export]
+macro_rules! irrelevant_static {
+    ($val:ident,$reason:literal,type = $tpe:ty,size = $size:expr $(,)?) => {
+        const _: () = ::core::assert!(
+            ::core::mem::size_of::<$tpe>() == $size,
+            ::core::concat!("The size of `", ::core::stringify!($tpe), "` changed, but: ", $reason)
+        );
+        $crate::irrelevant!($val, $reason, $tpe);
+    };
+}
+/// Registers `value` as the baseline called `key`, which values ignored later can be compared against, with the
+/// `matches_baseline(key)` assumption. Requires the `std` feature.
+///
+/// This allows capturing a value once, like a setting read at startup, and checking that it stays the same wherever
+/// it is ignored. Registering another value with the same key replaces the baseline. Violations report both the
+/// ignored value and the baseline, and where the baseline was registered.
+/// ```
+/// # use irrelevant::*;
+/// let locale = String::from("en-US");
+/// register_baseline!("locale", locale.clone());
+/// // ...
+/// irrelevant!(locale, "The locale can't change after startup, so it was already applied.", matches_baseline("locale"));
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! register_baseline {
+    ($key:expr, $value:expr $(,)?) => {
+        $crate::checks::__register_baseline(
+            $key,
+            $value,
+            $crate::Location {
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            },
+        )
+    };
+}
+/// Ignores a value like [`irrelevant`], reporting violated assumptions with the severity selected by `$mode`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __irrelevant_severity {
+    ($mode:ident, $val:ident $(,$reason:literal)?) => {
+        $crate::irrelevant!($val $(,$reason)?);
+    };
+    ($mode:ident, $val:ident,$reason:literal,$($assumption:tt)+) => {
+        $crate::__irrelevant_reason_args!($mode, $val, $reason, [] $($assumption)+);
+    };
+    ($mode:ident, [$($val:ident $(=> $cond:expr)?),+ $(,)?],$reason:literal) => {
+        $($($crate::__irrelevant_check!($mode, $reason, if $cond);)?)+
+        $($crate::__irrelevant_shadow!($val);)+
+    };
+    ($mode:ident, [$($val:ident),+ $(,)?],$reason:literal,$($assumption:tt)+) => {
+        $crate::__irrelevant_assume!($mode, [$($val),+], $reason, $($assumption)+);
+    };
+    ($mode:ident, $base:ident $(.$field:tt)+,$reason:literal,$($assumption:tt)+) => {
+        $crate::__irrelevant_reason_args!($mode, ($base $(.$field)+), $reason, [] $($assumption)+);
+    };
+    ($mode:ident, *$val:ident,$reason:literal,$($assumption:tt)+) => {
+        $crate::__irrelevant_reason_args!($mode, (*$val), $reason, [] $($assumption)+);
+    };
+    ($mode:ident, $($struct:ident)::+ { $($pattern:tt)+ } = $val:expr,$reason:literal $(,$($assumption:tt)+)?) => {
+        $crate::__irrelevant_fields!($mode, $($struct)::+ { $($pattern)+ }, $val, $reason $(,$($assumption)+)?);
+    };
+    ($mode:ident, $val:expr $(,$reason:literal)?) => {
+        $crate::irrelevant!($val $(,$reason)?);
+    };
+    ($mode:ident, $val:expr,$reason:literal,$($assumption:tt)+) => {{
+        let value = $val;
+        $crate::__irrelevant_reason_args!($mode, value, $reason, [] $($assumption)+);
+    }};
+}
+/// Ignores fields of a struct, by destructuring a reference to it. The assumption is checked about the whole struct,
+/// before the fields are shadowed.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __irrelevant_fields {
+    ($mode:ident, $($struct:ident)::+ { $($field:ident),+ $(,)? }, $val:expr, $reason:literal $(,$($assumption:tt)+)?) => {
+        let value = &$val;
+        $($crate::__irrelevant_reason_args!($mode, (*value), $reason, [] $($assumption)+);)?
+        let $($struct)::+ { $($field),+ } = value;
+        $($crate::__irrelevant_shadow!($field);)+
+    };
+    ($mode:ident, $($struct:ident)::+ { $($field:ident),+, .. }, $val:expr, $reason:literal $(,$($assumption:tt)+)?) => {
+        let value = &$val;
+        $($crate::__irrelevant_reason_args!($mode, (*value), $reason, [] $($assumption)+);)?
+        let $($struct)::+ { $($field),+, .. } = value;
+        $($crate::__irrelevant_shadow!($field);)+
+    };
+}
+/// Shadows an ignored value, so it can't be used by accident.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __irrelevant_shadow {
+    // The receiver of a method can't be shadowed.
+    (self) => {};
+    // Neither can a place, like a field of a struct.
+    (($($place:tt)+)) => {};
+    ($val:ident) => {
         let _ = $val;
-        let $val = irrelevant::ExplicitlyIgnoredValue;
+        let $val = $crate::ExplicitlyIgnoredValue;
         let _ = $val;
     };
+}
+/// Checks an assumption about an ignored value, and then shadows it. Shared by all the variants of [`irrelevant`],
+/// which select what happens on violation with `$mode` (`warn`, `panic`, `debug` or `result`, or `info`, `warning` and
+/// `error` for violations reported with a severity).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __irrelevant_assume {
+    // The value is a collection, assumed to contain an element.
+    ($mode:ident, $val:tt, $reason:tt, contains($item:expr)) => {
+        $crate::__irrelevant_check!($mode, $reason, if $val.contains($item));
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is an ordered map, assumed to have a specific smallest key.
+    ($mode:ident, $val:tt, $reason:tt, min_key == $key:expr) => {
+        $crate::__irrelevant_check!(
+            $mode,
+            $reason,
+            $crate::checks::eq(&$val.first_key_value().map(|(key, _)| key), &::core::option::Option::Some(&$key))
+        );
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is an ordered map, assumed to have a specific largest key.
+    ($mode:ident, $val:tt, $reason:tt, max_key == $key:expr) => {
+        $crate::__irrelevant_check!(
+            $mode,
+            $reason,
+            $crate::checks::eq(&$val.last_key_value().map(|(key, _)| key), &::core::option::Option::Some(&$key))
+        );
+        $crate::__irrelevant_shadow!($val);
+    };
+    // A condition or a type starting with a qualified path, like `<T as Trait>::MAX > x`, is not compared against a
+    // bound. A bound in parentheses, or a dereferenced one, is looked for before that, since it fails to parse as a type.
+    ($mode:ident, $val:tt, $reason:tt, < ($($bound:tt)*) $($rest:tt)*) => {
+        $crate::__irrelevant_check!(
+            $mode,
+            $reason,
+            if $val < (($($bound)*) $($rest)*),
+            else "{:?} is not smaller than {:?}", $val, (($($bound)*) $($rest)*)
+        );
+        $crate::__irrelevant_shadow!($val);
+    };
+    ($mode:ident, $val:tt, $reason:tt, < *$($bound:tt)+) => {
+        $crate::__irrelevant_check!(
+            $mode,
+            $reason,
+            if $val < (*$($bound)+),
+            else "{:?} is not smaller than {:?}", $val, (*$($bound)+)
+        );
+        $crate::__irrelevant_shadow!($val);
+    };
+    ($mode:ident, $val:tt, $reason:tt, < $qself:ty as $trait:path > :: $($rest:tt)+) => {
+        $crate::__irrelevant_guard!($mode, $val, $reason, [] < $qself as $trait > :: $($rest)+);
+    };
+    ($mode:ident, $val:tt, $reason:tt, < $qself:ty > :: $($rest:tt)+) => {
+        $crate::__irrelevant_guard!($mode, $val, $reason, [] < $qself > :: $($rest)+);
+    };
+    // The value is assumed to be equal to the expected one.
+    ($mode:ident, $val:tt, $reason:tt, == $expected:expr) => {
+        $crate::__irrelevant_check!($mode, $reason, $crate::checks::eq(&$val, &$expected));
+        $crate::__irrelevant_shadow!($val);
+    };
+    // No allocations are assumed to have been made since a marker, like around an operation ignoring the value.
+    ($mode:ident, $val:tt, $reason:tt, no_alloc_since($marker:expr)) => {
+        $crate::__irrelevant_check!($mode, $reason, $crate::checks::no_alloc_since(&$marker));
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is assumed to be in a range.
+    ($mode:ident, $val:tt, $reason:tt, in $range:expr) => {
+        $crate::__irrelevant_check!($mode, $reason, $crate::checks::within(&$val, &($range)));
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is assumed to be smaller than a bound.
+    ($mode:ident, $val:tt, $reason:tt, < $bound:expr) => {
+        $crate::__irrelevant_check!($mode, $reason, if $val < $bound, else "{:?} is not smaller than {:?}", $val, $bound);
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is assumed to be at most a bound.
+    ($mode:ident, $val:tt, $reason:tt, <= $bound:expr) => {
+        $crate::__irrelevant_check!($mode, $reason, if $val <= $bound, else "{:?} is larger than {:?}", $val, $bound);
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is assumed to be larger than a bound.
+    ($mode:ident, $val:tt, $reason:tt, > $bound:expr) => {
+        $crate::__irrelevant_check!($mode, $reason, if $val > $bound, else "{:?} is not larger than {:?}", $val, $bound);
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is assumed to be at least a bound.
+    ($mode:ident, $val:tt, $reason:tt, >= $bound:expr) => {
+        $crate::__irrelevant_check!($mode, $reason, if $val >= $bound, else "{:?} is smaller than {:?}", $val, $bound);
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is a float, assumed to be within a number of ULPs from the expected one.
+    ($mode:ident, $val:tt, $reason:tt, within_ulps($expected:expr, $ulps:expr)) => {
+        $crate::__irrelevant_check!($mode, $reason, $crate::checks::within_ulps($val, $expected, $ulps));
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is a pointer, assumed to point into a memory range.
+    ($mode:ident, $val:tt, $reason:tt, in_range($start:expr, $end:expr)) => {
+        $crate::__irrelevant_check!($mode, $reason, $crate::checks::in_range($val, $start, $end));
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The process is assumed to run in a specific timezone.
+    ($mode:ident, $val:tt, $reason:tt, tz == $tz:expr) => {
+        $crate::__irrelevant_check!($mode, $reason, $crate::checks::tz_is($tz));
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is assumed to come with a witness token, proving where it was created.
+    ($mode:ident, $val:tt, $reason:tt, witness($token:ty)) => {
+        $crate::__irrelevant_check!(
+            $mode,
+            $reason,
+            if ::core::option::Option::<&$token>::is_some(&$val.witness()),
+            else "the value was created without a {} witness", ::core::any::type_name::<$token>()
+        );
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is an integer, assumed to be a discriminant of a fieldless enum.
+    ($mode:ident, $val:tt, $reason:tt, valid_variant: $enum:ty) => {
+        $crate::__irrelevant_check!(
+            $mode,
+            $reason,
+            if <$enum as $crate::checks::Discriminant>::is_discriminant($val),
+            else "{:?} is not a discriminant of {}", $val, ::core::any::type_name::<$enum>()
+        );
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is a byte buffer, assumed to start with a big-endian `u32`.
+    ($mode:ident, $val:tt, $reason:tt, be_u32 == $expected:expr) => {
+        $crate::__irrelevant_check!($mode, $reason, $crate::checks::be_u32(&$val, $expected));
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is a byte buffer, assumed to start with a little-endian `u32`.
+    ($mode:ident, $val:tt, $reason:tt, le_u32 == $expected:expr) => {
+        $crate::__irrelevant_check!($mode, $reason, $crate::checks::le_u32(&$val, $expected));
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is a byte buffer, assumed not to contain a byte.
+    ($mode:ident, $val:tt, $reason:tt, no_byte($byte:expr)) => {
+        $crate::__irrelevant_check!($mode, $reason, $crate::checks::no_byte(&$val, $byte));
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is assumed to pass a validator, registered with `register_validator`.
+    ($mode:ident, $val:tt, $reason:tt, validator($name:expr)) => {
+        $crate::__irrelevant_check!($mode, $reason, $crate::checks::validator($name, &$val));
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is assumed to be equal to a baseline, registered with `register_baseline!`.
+    ($mode:ident, $val:tt, $reason:tt, matches_baseline($key:expr)) => {
+        $crate::__irrelevant_check!($mode, $reason, $crate::checks::matches_baseline($key, &$val));
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is a string, assumed to be accepted by a validator, whose results are cached.
+    ($mode:ident, $val:tt, $reason:tt, cached_valid($validator:expr)) => {
+        $crate::__irrelevant_check!($mode, $reason, $crate::checks::cached_valid($validator, &$val));
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is an iterator, assumed to yield an exact number of items. This consumes the iterator.
+    ($mode:ident, $val:tt, $reason:tt, count == $expected:expr) => {
+        $crate::__irrelevant_check!($mode, $reason, $crate::checks::count($val, $expected));
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is a pointer, assumed to be aligned for atomic access to a type.
+    ($mode:ident, $val:tt, $reason:tt, atomic_aligned::<$ty:ty>()) => {
+        $crate::__irrelevant_check!($mode, $reason, $crate::checks::atomic_aligned::<$ty, _>($val));
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is a string, assumed to start with one of the prefixes.
+    ($mode:ident, $val:tt, $reason:tt, starts_with_any $prefixes:expr) => {
+        $crate::__irrelevant_check!($mode, $reason, $crate::checks::starts_with_any(&$val, $prefixes));
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is assumed to have the same layout as another type. This is checked at compile time.
+    ($mode:ident, $val:tt, $reason:tt, same_layout_as $ty:ty) => {
+        $crate::checks::same_layout_as::<$ty, _>(&$val);
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is assumed to match a snapshot stored in a file.
+    ($mode:ident, $val:tt, $reason:tt, snapshot $path:expr) => {
+        $crate::__irrelevant_check!($mode, $reason, $crate::checks::snapshot(&$val, $path));
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is a byte buffer, assumed to have a specific CRC-32 checksum.
+    ($mode:ident, $val:tt, $reason:tt, crc32 == $expected:expr) => {
+        $crate::__irrelevant_check!(
+            $mode,
+            $reason,
+            if $crate::checks::crc32(&$val) == $expected,
+            else "the checksum is {:#010x}", $crate::checks::crc32(&$val)
+        );
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is a bitset, assumed to have a specific number of set bits.
+    ($mode:ident, $val:tt, $reason:tt, popcount == $expected:expr) => {
+        $crate::__irrelevant_check!(
+            $mode,
+            $reason,
+            if $crate::checks::Popcount::popcount(&$val) == $expected,
+            else "{} bits are set", $crate::checks::Popcount::popcount(&$val)
+        );
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is a reading, assumed to be physically plausible.
+    ($mode:ident, $val:tt, $reason:tt, plausible($min:expr, $max:expr)) => {
+        $crate::__irrelevant_check!($mode, $reason, $crate::checks::plausible($val, $min, $max));
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is a string, assumed to be short enough to be stored inline.
+    ($mode:ident, $val:tt, $reason:tt, inline_capable($max:expr)) => {
+        $crate::__irrelevant_check!(
+            $mode,
+            $reason,
+            if <_ as ::core::convert::AsRef<str>>::as_ref(&$val).len() <= $max,
+            else "{} bytes long, at most {} fit inline", <_ as ::core::convert::AsRef<str>>::as_ref(&$val).len(), $max
+        );
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is only irrelevant under a combination of cfg flags.
+    ($mode:ident, $val:tt, $reason:tt, cfg_consistent($($pred:tt)+)) => {
+        $crate::__irrelevant_check!(
+            $mode,
+            $reason,
+            if ::core::cfg!($($pred)+),
+            else "the cfg flags do not satisfy `{}`", ::core::stringify!($($pred)+)
+        );
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is a collection, assumed to use little heap memory.
+    ($mode:ident, $val:tt, $reason:tt, heap_bytes <= $max:expr) => {
+        $crate::__irrelevant_check!(
+            $mode,
+            $reason,
+            if $crate::checks::HeapBytes::heap_bytes(&$val) <= $max,
+            else "about {} bytes are allocated, more than {}", $crate::checks::HeapBytes::heap_bytes(&$val), $max
+        );
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The values are coordinates, assumed to be within a grid.
+    ($mode:ident, [$x:ident, $y:ident], $reason:tt, in_grid($width:expr, $height:expr)) => {
+        $crate::__irrelevant_check!(
+            $mode,
+            $reason,
+            if $x < $width && $y < $height,
+            else "({}, {}) is outside of a {}x{} grid", $x, $y, $width, $height
+        );
+        $crate::__irrelevant_shadow!($x);
+        $crate::__irrelevant_shadow!($y);
+    };
+    // The value is an integer, assumed not to overflow when adding another one.
+    ($mode:ident, $val:tt, $reason:tt, no_overflow_add($rhs:expr)) => {
+        $crate::__irrelevant_check!(
+            $mode,
+            $reason,
+            if $val.checked_add($rhs).is_some(),
+            else "{} + {} overflows", $val, $rhs
+        );
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is an integer, assumed not to overflow when subtracting another one.
+    ($mode:ident, $val:tt, $reason:tt, no_overflow_sub($rhs:expr)) => {
+        $crate::__irrelevant_check!(
+            $mode,
+            $reason,
+            if $val.checked_sub($rhs).is_some(),
+            else "{} - {} overflows", $val, $rhs
+        );
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is an integer, assumed not to overflow when multiplying by another one.
+    ($mode:ident, $val:tt, $reason:tt, no_overflow_mul($rhs:expr)) => {
+        $crate::__irrelevant_check!(
+            $mode,
+            $reason,
+            if $val.checked_mul($rhs).is_some(),
+            else "{} * {} overflows", $val, $rhs
+        );
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is a float, assumed to be close to the expected one.
+    ($mode:ident, $val:tt, $reason:tt, close_to($expected:expr)) => {
+        $crate::__irrelevant_assume!($mode, $val, $reason, close_to($expected, rtol = 1e-5, atol = 1e-8));
+    };
+    ($mode:ident, $val:tt, $reason:tt, close_to($expected:expr, rtol = $rtol:expr, atol = $atol:expr)) => {
+        $crate::__irrelevant_check!($mode, $reason, $crate::checks::close_to($val, $expected, $rtol, $atol));
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is a `Result`, assumed to be a specific `Ok` value.
+    ($mode:ident, $val:tt, $reason:tt, ok == $expected:expr) => {
+        $crate::__irrelevant_check!($mode, $reason, $crate::checks::ok_eq(&$val, &$expected));
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is an `Option`, assumed to be `None` whenever a condition holds.
+    ($mode:ident, $val:tt, $reason:tt, none_when($cond:expr)) => {
+        $crate::__irrelevant_check!($mode, $reason, $crate::checks::none_when(&$val, $cond));
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The process is assumed to run in a specific environment, like production.
+    ($mode:ident, $val:tt, $reason:tt, env_is($expected:expr)) => {
+        $crate::__irrelevant_assume!($mode, $val, $reason, env_is($expected, var = "APP_ENV"));
+    };
+    ($mode:ident, $val:tt, $reason:tt, env_is($expected:expr, var = $var:expr)) => {
+        $crate::__irrelevant_check!($mode, $reason, $crate::checks::env_is($var, $expected));
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is a time, assumed to be after a fixed point.
+    ($mode:ident, $val:tt, $reason:tt, after($min_secs:expr)) => {
+        $crate::__irrelevant_check!($mode, $reason, $crate::checks::after(&$val, $min_secs));
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is a slice of floats, assumed to be elementwise close to the expected one.
+    ($mode:ident, $val:tt, $reason:tt, elementwise_close($expected:expr, $tol:expr)) => {
+        $crate::__irrelevant_check!($mode, $reason, $crate::checks::elementwise_close(&$val, &$expected, $tol));
+        $crate::__irrelevant_shadow!($val);
+    };
+    // A value is ignored because of several assumptions, which are all checked before it is shadowed.
+    ($mode:ident, $val:tt, $reason:tt, [$($cond:expr),+ $(,)?]) => {
+        $crate::__irrelevant_conditions!($mode, $reason, 0usize, $($cond),+);
+        $crate::__irrelevant_shadow!($val);
+    };
+    // A value is ignored because of an assumption, and is reported with its `Debug` representation if it doesn't hold.
+    ($mode:ident, $val:tt, $reason:tt, dbg $cond:ident) => {
+        $crate::__irrelevant_assume!($mode, $val, $reason, dbg {
+            #[allow(unused_imports)]
+            use $crate::checks::Assumptions as _;
+            $val.$cond()
+        });
+    };
+    ($mode:ident, $val:tt, $reason:tt, dbg $cond:expr) => {
+        $crate::__irrelevant_check!($mode, $reason, dbg $val, $cond);
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is assumed to be of a specific type, named like `core::any::type_name` names it.
+    ($mode:ident, $val:tt, $reason:tt, type_name == $expected:expr) => {
+        $crate::__irrelevant_check!(
+            $mode,
+            $reason,
+            if ::core::any::type_name_of_val(&$val) == $expected,
+            else "the type is {}", ::core::any::type_name_of_val(&$val)
+        );
+        $crate::__irrelevant_shadow!($val);
+    };
+    // The value is a path, assumed to be within a root directory.
+    ($mode:ident, $val:tt, $reason:tt, under($root:expr)) => {
+        $crate::__irrelevant_check!($mode, $reason, $crate::checks::under(&$val, &$root));
+        $crate::__irrelevant_shadow!($val);
+    };
+    // A value is ignored because of an assumption, which is a method of the value or a named assumption.
+    ($mode:ident, $val:tt, $reason:tt, $cond:ident) => {
+        $crate::__irrelevant_check!($mode, $reason, {
+            #[allow(unused_imports)]
+            use $crate::checks::Assumptions as _;
+            $val.$cond()
+        });
+        $crate::__irrelevant_shadow!($val);
+    };
+    // A value is ignored because of a condition or its type. The condition may be guarded by a cheaper check, which is
+    // looked for first, since parsing a guard like `x < N` as a type fails.
+    ($mode:ident, $val:tt, $reason:tt, $($assumption:tt)+) => {
+        $crate::__irrelevant_guard!($mode, $val, $reason, [] $($assumption)+);
+    };
+}
+/// Looks for a cheap check guarding an assumption, separated from it by a `=>`. Assumptions without a guard are passed
+/// on to [`__irrelevant_condition`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __irrelevant_guard {
+    // A value is ignored because of an assumption, which is only fully checked when a cheaper check fails.
+    ($mode:ident, $val:tt, $reason:tt, [$($cheap:tt)+] => $expensive:expr) => {
+        $crate::__irrelevant_check!($mode, $reason, if ($($cheap)+) || $expensive);
+        $crate::__irrelevant_shadow!($val);
+    };
+    ($mode:ident, $val:tt, $reason:tt, [$($assumption:tt)+]) => {
+        $crate::__irrelevant_condition!($mode, $val, $reason, $($assumption)+);
+    };
+    ($mode:ident, $val:tt, $reason:tt, [$($cheap:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__irrelevant_guard!($mode, $val, $reason, [$($cheap)* $next] $($rest)*);
+    };
+}
+/// Checks an assumption without a guard, which is either a condition or the type of the value.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __irrelevant_condition {
+    // A value is ignored because its type is not relevant, and is a raw pointer.
+    ($mode:ident, $val:tt, $reason:tt, *const $tpe:ty) => {
+        let _: *const $tpe = $val;
+        $crate::__irrelevant_shadow!($val);
+    };
+    ($mode:ident, $val:tt, $reason:tt, *mut $tpe:ty) => {
+        let _: *mut $tpe = $val;
+        $crate::__irrelevant_shadow!($val);
+    };
+    // A value is ignored because of an assumption about what it points to, like `*boxed == 0`. Otherwise, the `*`
+    // would be parsed as the start of a raw pointer type.
+    ($mode:ident, $val:tt, $reason:tt, *$($cond:tt)+) => {
+        $crate::__irrelevant_check!($mode, $reason, if *$($cond)+);
+        $crate::__irrelevant_shadow!($val);
+    };
     // A value is ignored because its type is not relevant.
-    ($val:ident,$reason:literal,$tpe:ty) => {
+    ($mode:ident, $val:tt, $reason:tt, $tpe:ty) => {
         let _: $tpe = $val;
-        let $val = irrelevant::ExplicitlyIgnoredValue;
-        let _ = $val;
+        $crate::__irrelevant_shadow!($val);
     };
     // A value is ignored because of an assumption.
-    ($val:ident,$reason:literal,$cond:expr) => {
+    ($mode:ident, $val:tt, $reason:tt, $cond:expr) => {
+        $crate::__irrelevant_check!($mode, $reason, if $cond);
+        $crate::__irrelevant_shadow!($val);
+    };
+}
+/// Checks a list of conditions one by one, reporting the index of each one which doesn't hold.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __irrelevant_conditions {
+    ($mode:ident, $reason:tt, $index:expr, $cond:expr $(, $rest:expr)*) => {
+        $crate::__irrelevant_check!(
+            $mode,
+            $reason,
+            if $cond,
+            else "assumption {} (`{}`) doesn't hold", $index, ::core::stringify!($cond)
+        );
+        $crate::__irrelevant_conditions!($mode, $reason, $index + 1 $(, $rest)*);
+    };
+    ($mode:ident, $reason:tt, $index:expr) => {};
+}
+/// Runs a single check, and reports a violation according to `$mode`.
+///
+/// A check is either a condition (`if $cond`), optionally with lazily formatted details (`if $cond, else "{}", x`),
+/// or an expression evaluating to an [`Outcome`](checks::Outcome): a `bool`, or `Result<(), impl Display>`, where the
+/// error describes the violation.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __irrelevant_check {
+    (debug, $reason:tt, $($check:tt)+) => {
+        $crate::__irrelevant_reason_len!($reason);
         #[cfg(debug_assertions)]
         {
-            if $cond {
-                let file = file!();
-                let line = line!();
-                let column = column!();
-                eprintln!("[{file}:{line}:{column}] Assumption violated:{}", $reason)
+            $crate::__irrelevant_check!(warn, $reason, $($check)+);
+        }
+    };
+    ($mode:ident, $reason:tt, if $cond:expr) => {
+        $crate::__irrelevant_reason_len!($reason);
+        $crate::__irrelevant_checked! {
+            if !($cond) {
+                $crate::__irrelevant_violated!($mode, $reason);
             }
         }
-        let _ = $val;
-        let $val = irrelevant::ExplicitlyIgnoredValue;
-        let _ = $val;
+    };
+    ($mode:ident, $reason:tt, if $cond:expr, else $($details:tt)+) => {
+        $crate::__irrelevant_reason_len!($reason);
+        $crate::__irrelevant_checked! {
+            if !($cond) {
+                $crate::__irrelevant_violated!($mode, $reason, $($details)+);
+            }
+        }
+    };
+    ($mode:ident, $reason:tt, dbg $val:tt, $outcome:expr) => {
+        $crate::__irrelevant_reason_len!($reason);
+        $crate::__irrelevant_checked! {
+            match $crate::checks::Outcome::into_result($outcome) {
+                ::core::result::Result::Ok(()) => {}
+                ::core::result::Result::Err(::core::option::Option::None) => {
+                    $crate::__irrelevant_violated!($mode, $reason, "value = {:?}", $val);
+                }
+                ::core::result::Result::Err(::core::option::Option::Some(details)) => {
+                    $crate::__irrelevant_violated!($mode, $reason, "{}, value = {:?}", details, $val);
+                }
+            }
+        }
+    };
+    ($mode:ident, $reason:tt, $outcome:expr) => {
+        $crate::__irrelevant_reason_len!($reason);
+        $crate::__irrelevant_checked! {
+            match $crate::checks::Outcome::into_result($outcome) {
+                ::core::result::Result::Ok(()) => {}
+                ::core::result::Result::Err(::core::option::Option::None) => {
+                    $crate::__irrelevant_violated!($mode, $reason);
+                }
+                ::core::result::Result::Err(::core::option::Option::Some(details)) => {
+                    $crate::__irrelevant_violated!($mode, $reason, "{}", details);
+                }
+            }
+        }
+    };
+}
+/// Expands to the check it is given, which is only evaluated with the `checks` feature.
+#[cfg(feature = "checks")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __irrelevant_checked {
+    ($($check:tt)*) => {
+        $($check)*
+    };
+}
+/// Without the `checks` feature, the checks are never run, so their conditions are never evaluated. They are still
+/// type-checked, so the variables only used by the conditions don't become unused.
+#[cfg(not(feature = "checks"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __irrelevant_checked {
+    ($($check:tt)*) => {
+        if false {
+            $($check)*
+        }
+    };
+}
+/// Reports a violated assumption, either by logging it, by panicking, or by returning an error.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __irrelevant_violated {
+    (warn, $($violation:tt)+) => {
+        $crate::__irrelevant_violated!(report ::core::option::Option::None, $($violation)+)
+    };
+    (info, $($violation:tt)+) => {
+        $crate::__irrelevant_violated!(report ::core::option::Option::Some($crate::Severity::Info), $($violation)+)
+    };
+    (warning, $($violation:tt)+) => {
+        $crate::__irrelevant_violated!(report ::core::option::Option::Some($crate::Severity::Warning), $($violation)+)
+    };
+    (error, $($violation:tt)+) => {
+        $crate::__irrelevant_violated!(report ::core::option::Option::Some($crate::Severity::Error), $($violation)+)
+    };
+    (report $severity:expr, $reason:tt) => {
+        $crate::__report_violation(
+            file!(),
+            line!(),
+            column!(),
+            $severity,
+            $crate::__irrelevant_reason!($reason),
+            ::core::option::Option::None,
+        )
+    };
+    (report $severity:expr, $reason:tt, $($details:tt)+) => {
+        $crate::__report_violation(
+            file!(),
+            line!(),
+            column!(),
+            $severity,
+            $crate::__irrelevant_reason!($reason),
+            ::core::option::Option::Some(::core::format_args!($($details)+)),
+        )
+    };
+    // The details may borrow the ignored value, so they can't be returned, and are dropped.
+    (result, $reason:tt $(, $($details:tt)+)?) => {
+        let file = file!();
+        let line = line!();
+        let column = column!();
+        $crate::__record_violation(file, line, column, $crate::__irrelevant_reason!($reason));
+        return ::core::result::Result::Err(::core::convert::From::from($crate::IgnoreViolation {
+            file,
+            line,
+            column,
+            reason: $crate::__irrelevant_reason!(template $reason),
+        }));
+    };
+    (panic, $reason:tt) => {
+        $crate::__panic_violation(
+            file!(),
+            line!(),
+            column!(),
+            $crate::__irrelevant_reason!($reason),
+            ::core::option::Option::None,
+        )
+    };
+    (panic, $reason:tt, $($details:tt)+) => {
+        $crate::__panic_violation(
+            file!(),
+            line!(),
+            column!(),
+            $crate::__irrelevant_reason!($reason),
+            ::core::option::Option::Some(::core::format_args!($($details)+)),
+        )
+    };
+}
+/// Formats the reason for ignoring a value, which is either a literal or a literal with format arguments.
+///
+/// The `template` is the literal itself, without the arguments.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __irrelevant_reason {
+    (template $reason:literal) => {
+        $reason
+    };
+    (template ($reason:literal, $($args:tt)+)) => {
+        $reason
+    };
+    ($reason:literal) => {
+        ::core::format_args!("{}", $reason)
+    };
+    (($reason:literal, $($args:tt)+)) => {
+        ::core::format_args!($reason, $($args)+)
+    };
+}
+/// Splits the format arguments of a reason, which end with a `;`, from the assumption.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __irrelevant_reason_args {
+    ($mode:ident, $val:tt, $reason:literal, [$($assumption:tt)+]) => {
+        $crate::__irrelevant_assume!($mode, $val, $reason, $($assumption)+);
+    };
+    ($mode:ident, $val:tt, $reason:literal, [$($args:tt)+] ; $($assumption:tt)+) => {
+        $crate::__irrelevant_assume!($mode, $val, ($reason, $($args)+), $($assumption)+);
+    };
+    ($mode:ident, $val:tt, $reason:literal, [$($munched:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__irrelevant_reason_args!($mode, $val, $reason, [$($munched)* $next] $($rest)*);
     };
 }
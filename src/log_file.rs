@@ -0,0 +1,90 @@
+use crate::{Location, ViolationRecord};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::num::NonZeroU32;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
+
+/// The file violations are appended to, and how much of it is used.
+struct ViolationFile {
+    path: PathBuf,
+    file: File,
+    len: u64,
+    max_bytes: u64,
+}
+impl ViolationFile {
+    /// Appends a line to the file, rotating it first if the line would not fit.
+    fn append(&mut self, line: &str) -> io::Result<()> {
+        let line_len = line.len() as u64;
+        if self.len > 0 && self.len + line_len > self.max_bytes {
+            fs::rename(&self.path, rotated_path(&self.path))?;
+            self.file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+            self.len = 0;
+        }
+        self.file.write_all(line.as_bytes())?;
+        self.len += line_len;
+        Ok(())
+    }
+}
+static VIOLATION_FILE: Mutex<Option<ViolationFile>> = Mutex::new(None);
+static SAMPLE_EVERY: AtomicU32 = AtomicU32::new(1);
+static SAMPLE_COUNTER: AtomicU32 = AtomicU32::new(0);
+/// Returns the path a full violation file is moved to: the same path, with `.1` appended.
+fn rotated_path(path: &Path) -> PathBuf {
+    let mut rotated = path.as_os_str().to_owned();
+    rotated.push(".1");
+    PathBuf::from(rotated)
+}
+/// Appends all the following violated assumptions to the file at `path`, creating it if needed.
+///
+/// Once the file would grow past `max_bytes`, it is moved to the same path with `.1` appended, replacing the previous
+/// one, and a new file is started. So, at most about `2 * max_bytes` are kept on disk, which makes this suitable for
+/// long-running services. Use [`set_violation_sampling`] to only keep some of the violations.
+///
+/// Each violation is written as a single line, in the same format as the messages printed to `stderr`.
+/// ```no_run
+/// # use irrelevant::*;
+/// set_violation_file("violations.log", 1024 * 1024).unwrap();
+/// let sauces = ["ketchup"];
+/// irrelevant!(sauces, "No sauces should come with a drink!", is_empty);
+/// ```
+pub fn set_violation_file(path: impl AsRef<Path>, max_bytes: u64) -> io::Result<()> {
+    let path = path.as_ref().to_owned();
+    let file = OpenOptions::new().create(true).append(true).open(&path)?;
+    let len = file.metadata()?.len();
+    *VIOLATION_FILE.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(ViolationFile {
+        path,
+        file,
+        len,
+        max_bytes,
+    });
+    Ok(())
+}
+/// Stops appending violated assumptions to the file set with [`set_violation_file`].
+pub fn clear_violation_file() {
+    *VIOLATION_FILE.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = None;
+}
+/// Only appends one in every `every` violated assumptions to the file set with [`set_violation_file`].
+///
+/// By default, all of them are appended. The first violation after changing the sampling is always appended.
+/// Sampling doesn't affect the messages printed to `stderr`.
+pub fn set_violation_sampling(every: NonZeroU32) {
+    SAMPLE_EVERY.store(every.get(), Ordering::Relaxed);
+    SAMPLE_COUNTER.store(0, Ordering::Relaxed);
+}
+/// Appends a violation to the violation file, if one is set and the violation is sampled.
+pub(crate) fn log_violation(record: &ViolationRecord) {
+    let mut violation_file = VIOLATION_FILE.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let Some(violation_file) = violation_file.as_mut() else {
+        return;
+    };
+    let every = SAMPLE_EVERY.load(Ordering::Relaxed);
+    if SAMPLE_COUNTER.fetch_add(1, Ordering::Relaxed) % every != 0 {
+        return;
+    }
+    let Location { file, line, column } = record.location;
+    let line = format!("[{file}:{line}:{column}] Assumption violated: {}\n", record.reason);
+    // There is nowhere left to report a failure to log a violation to, so it is dropped.
+    let _ = violation_file.append(&line);
+}
@@ -0,0 +1,41 @@
+/// A value which has to be ignored explicitly, with [`irrelevant`](crate::irrelevant).
+///
+/// Functions returning values their callers are expected to ignore can wrap them in it. It is `#[must_use]`, so
+/// dropping it without ignoring it, like by calling such a function as a statement, triggers the `unused_must_use`
+/// warning. The `must` prefix of [`irrelevant`](crate::irrelevant) unwraps it, and ignores the inner value, checking
+/// assumptions about it as usual.
+/// ```
+/// # use irrelevant::*;
+/// fn flush(buffer: &mut Vec<u8>) -> MustIgnore<usize> {
+///     let written = buffer.len();
+///     buffer.clear();
+///     MustIgnore::new(written)
+/// }
+/// let mut buffer = vec![1, 2, 3];
+/// let written = flush(&mut buffer);
+/// irrelevant!(must written, "The buffer is always flushed whole.", written == 3);
+/// irrelevant!(must flush(&mut buffer), "Flushing an empty buffer writes nothing.", == 0);
+/// ```
+/// Forgetting to ignore the value triggers a warning:
+/// ```compile_fail
+/// # #![deny(unused_must_use)]
+/// # use irrelevant::*;
+/// # fn flush(buffer: &mut Vec<u8>) -> MustIgnore<usize> {
+/// #     MustIgnore::new(buffer.len())
+/// # }
+/// # let mut buffer = vec![1, 2, 3];
+/// flush(&mut buffer);
+/// ```
+#[must_use = "the value has to be ignored explicitly, with `irrelevant!(must ...)`"]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct MustIgnore<T>(T);
+impl<T> MustIgnore<T> {
+    /// Wraps a value, which has to be ignored explicitly.
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+    /// Unwraps the value, so it can be ignored.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
@@ -0,0 +1,71 @@
+/// The maximum length of a reason in bytes, set by the `IRRELEVANT_MAX_REASON_LEN` environment variable at build time.
+#[cfg(feature = "max-reason-len")]
+#[doc(hidden)]
+pub const __MAX_REASON_LEN: usize = match option_env!("IRRELEVANT_MAX_REASON_LEN") {
+    Some(len) => parse_len(len),
+    None => 128,
+};
+/// Parses a length, at compile time.
+#[cfg(feature = "max-reason-len")]
+const fn parse_len(len: &str) -> usize {
+    let digits = len.as_bytes();
+    assert!(!digits.is_empty(), "IRRELEVANT_MAX_REASON_LEN must be a number");
+    let mut parsed = 0;
+    let mut i = 0;
+    while i < digits.len() {
+        assert!(digits[i].is_ascii_digit(), "IRRELEVANT_MAX_REASON_LEN must be a number");
+        parsed = parsed * 10 + (digits[i] - b'0') as usize;
+        i += 1;
+    }
+    parsed
+}
+/// Asserts at compile time that the reason for ignoring a value is at most [`__MAX_REASON_LEN`] bytes long.
+/// ```
+/// # use irrelevant::*;
+/// let compressed = false;
+/// irrelevant!(compressed, "Responses are compressed by the proxy.", !compressed);
+/// ```
+/// Longer reasons fail to compile, with the error "The reason is longer than IRRELEVANT_MAX_REASON_LEN: ...".
+/// ```compile_fail,E0080
+/// # use irrelevant::*;
+/// let compressed = false;
+/// irrelevant!(
+///     compressed,
+///     "Responses are compressed by the proxy, which is configured by the operations team, so the application never \
+///      has to compress anything itself.",
+///     !compressed
+/// );
+/// ```
+/// The same goes for reasons with format arguments, which are limited before they are formatted.
+/// ```compile_fail,E0080
+/// # use irrelevant::*;
+/// let (compressed, proxy) = (false, "nginx");
+/// irrelevant!(
+///     compressed,
+///     "Responses are compressed by {}, which is configured by the operations team, so the application never has to \
+///      compress anything itself.",
+///     proxy;
+///     !compressed
+/// );
+/// ```
+#[cfg(feature = "max-reason-len")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __irrelevant_reason_len {
+    ($reason:literal) => {
+        const _: () = if $reason.len() > $crate::__MAX_REASON_LEN {
+            // The reason may contain braces, so it is an argument rather than a part of the format string.
+            ::core::panic!("{}", ::core::concat!("The reason is longer than IRRELEVANT_MAX_REASON_LEN: ", $reason));
+        };
+    };
+    (($reason:literal, $($args:tt)+)) => {
+        $crate::__irrelevant_reason_len!($reason);
+    };
+}
+/// Without the `max-reason-len` feature, reasons can be of any length.
+#[cfg(not(feature = "max-reason-len"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __irrelevant_reason_len {
+    ($reason:tt) => {};
+}
@@ -0,0 +1,61 @@
+use std::cell::Cell;
+use std::thread::LocalKey;
+
+/// Tracks how deep the recursion is, for [`recursion_guard`](crate::recursion_guard).
+///
+/// Entering a function increases the depth of the current thread, and dropping the guard decreases it again.
+pub struct RecursionGuard {
+    depth: &'static LocalKey<Cell<usize>>,
+}
+impl RecursionGuard {
+    #[doc(hidden)]
+    pub fn enter(depth: &'static LocalKey<Cell<usize>>) -> Self {
+        depth.with(|depth| depth.set(depth.get() + 1));
+        Self { depth }
+    }
+    /// Returns the number of guarded calls the current thread is nested in, including this one.
+    pub fn depth(&self) -> usize {
+        self.depth.with(Cell::get)
+    }
+}
+impl Drop for RecursionGuard {
+    fn drop(&mut self) {
+        self.depth.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+/// Checks that a recursive function never recurses deeper than a limit, without passing the depth around explicitly.
+///
+/// The depth is tracked separately for every thread and every place this macro is used in, until the end of the
+/// enclosing scope. When the limit is exceeded, an error message is printed to `stderr`, just like with
+/// [`irrelevant`](crate::irrelevant). Without the `checks` feature, the depth is not tracked at all.
+/// ```
+/// # use irrelevant::*;
+/// struct Node {
+///     children: Vec<Node>,
+/// }
+/// fn count(node: &Node) -> usize {
+///     recursion_guard!(64, "Trees are balanced, so they are never more than 64 levels deep.");
+///     1 + node.children.iter().map(count).sum::<usize>()
+/// }
+/// # assert_eq!(count(&Node { children: vec![Node { children: vec![] }] }), 2);
+/// ```
+#[macro_export]
+macro_rules! recursion_guard {
+    ($limit:expr, $reason:literal) => {
+        // Without the `checks` feature, the depth isn't tracked either.
+        $crate::__irrelevant_checked! {
+            let guard = {
+                ::std::thread_local! {
+                    static DEPTH: ::core::cell::Cell<usize> = const { ::core::cell::Cell::new(0) };
+                }
+                $crate::RecursionGuard::enter(&DEPTH)
+            };
+            $crate::__irrelevant_check!(
+                warn,
+                $reason,
+                if guard.depth() <= $limit,
+                else "the recursion is {} calls deep", guard.depth()
+            );
+        }
+    };
+}
@@ -0,0 +1,69 @@
+/// Runs a check confirming a resource was released, when dropped. Created by
+/// [`scoped_irrelevant`](crate::scoped_irrelevant).
+///
+/// The token does not own the guard, so a guard that was leaked keeps the resource busy, and the check reports it.
+pub struct ScopedIgnore<F: FnOnce()> {
+    on_release: Option<F>,
+}
+impl<F: FnOnce()> ScopedIgnore<F> {
+    #[doc(hidden)]
+    pub fn new(on_release: F) -> Self {
+        Self {
+            on_release: Some(on_release),
+        }
+    }
+}
+impl<F: FnOnce()> Drop for ScopedIgnore<F> {
+    fn drop(&mut self) {
+        if let Some(on_release) = self.on_release.take() {
+            on_release();
+        }
+    }
+}
+/// A version of [`irrelevant`](crate::irrelevant) for guards, which should release a resource at the end of the scope.
+///
+/// Like [`irrelevant`](crate::irrelevant), the guard is shadowed, and is still dropped at the end of the enclosing
+/// scope. Right after that, the [`ScopedIgnore`] token drops, and checks the `released` condition. If it doesn't hold,
+/// the resource outlived its expected scope (for example, because the guard was leaked), and an error message is
+/// printed to `stderr`.
+/// ```
+/// # use irrelevant::*;
+/// use std::sync::Mutex;
+/// let counter = Mutex::new(0);
+/// {
+///     let guard = counter.lock().unwrap();
+///     scoped_irrelevant!(guard, "The lock only stops others from counting.", || counter.try_lock().is_ok());
+///     // `guard` has been ignored, but the mutex stays locked until the end of the scope.
+///     assert!(counter.try_lock().is_err());
+/// }
+/// ```
+/// The token can be bound with `let`, and moved elsewhere, to check the resource when it is dropped instead.
+/// ```
+/// # use irrelevant::*;
+/// # use std::sync::Mutex;
+/// let counter = Mutex::new(0);
+/// let token = {
+///     let guard = counter.lock().unwrap();
+///     scoped_irrelevant!(let token = guard, "The lock only stops others from counting.", || counter.try_lock().is_ok());
+///     token
+/// };
+/// drop(token);
+/// ```
+#[macro_export]
+macro_rules! scoped_irrelevant {
+    (let $token:ident = $val:ident,$reason:literal,$released:expr) => {
+        let released = $released;
+        // The token is created before the guard is moved, so that the guard is dropped first.
+        let $token = $crate::ScopedIgnore::new(move || {
+            $crate::__irrelevant_check!(warn, $reason, if released(), else "the resource outlived its scope");
+        });
+        let _ = &$token;
+        let guard = $val;
+        let _ = &guard;
+        let $val = $crate::ExplicitlyIgnoredValue;
+        let _ = $val;
+    };
+    ($val:ident,$reason:literal,$released:expr) => {
+        $crate::scoped_irrelevant!(let token = $val, $reason, $released);
+    };
+}
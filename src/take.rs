@@ -0,0 +1,86 @@
+/// A version of [`irrelevant`](crate::irrelevant), which moves the ignored value into a new binding, instead of
+/// discarding it.
+///
+/// This is meant for values which are irrelevant to the code around them, but still have to be handed off exactly once,
+/// for example to a destructor or a logging sink. The assumptions are checked first, then the value is **moved** into
+/// the new binding, and the original one is shadowed, just like with [`irrelevant`](crate::irrelevant). So, the value
+/// can only be used deliberately, through the new binding, and is dropped when that binding goes out of scope (unless it
+/// is moved further).
+/// ```
+/// # use irrelevant::*;
+/// fn close(connection: Vec<u8>, pending: Vec<u8>) {
+///     irrelevant_take!(let flushed = pending, "Pending writes are flushed before closing.", pending.is_empty());
+///     drop(flushed);
+///     drop(connection);
+/// }
+/// close(vec![1], vec![]);
+/// ```
+/// ```compile_fail
+/// # use irrelevant::*;
+/// # let pending: Vec<u8> = Vec::new();
+/// irrelevant_take!(let flushed = pending, "Pending writes are flushed before closing.");
+/// // `pending` has been ignored, so this variable can't be used here!
+/// println!("{}", pending.len());
+/// ```
+/// The macro has to introduce the new binding itself (with `let`), since a macro used as an expression can't shadow the
+/// original binding.
+///
+/// It can still be used as an expression, evaluating to the value, like `let taken = irrelevant_take!(val, "reason")`.
+/// The assumptions are checked the same way, and the value is **moved** out of the original binding. That binding is
+/// not shadowed, though, so only values which aren't `Copy` are accepted: they can't be used again after being moved.
+/// For `Copy` values, use the `let` form.
+/// ```
+/// # use irrelevant::*;
+/// fn close(connection: Vec<u8>, pending: Vec<u8>) {
+///     drop(irrelevant_take!(pending, "Pending writes are flushed before closing.", pending.is_empty()));
+///     drop(connection);
+/// }
+/// close(vec![1], vec![]);
+/// ```
+/// ```compile_fail
+/// # use irrelevant::*;
+/// # let pending: Vec<u8> = Vec::new();
+/// let flushed = irrelevant_take!(pending, "Pending writes are flushed before closing.");
+/// // `pending` has been moved out, so this variable can't be used here!
+/// println!("{}", pending.len());
+/// ```
+/// ```compile_fail
+/// # use irrelevant::*;
+/// let retries = 3;
+/// // `retries` is `Copy`, so it could still be used after this.
+/// let taken = irrelevant_take!(retries, "Retries are handled by the caller.");
+/// ```
+#[macro_export]
+macro_rules! irrelevant_take {
+    (let $taken:ident = $val:ident,$reason:literal) => {
+        let $taken = $val;
+        $crate::irrelevant!($val, $reason);
+    };
+    (let $taken:ident = $val:ident,$reason:literal,$($assumption:tt)+) => {
+        // The assumption shadows the value only within this block, so it can still be moved out afterwards.
+        {
+            $crate::irrelevant!($val, $reason, $($assumption)+);
+        }
+        let $taken = $val;
+        $crate::irrelevant!($val, $reason);
+    };
+    ($val:ident,$reason:literal $(,$($assumption:tt)+)?) => {{
+        $crate::__assert_not_copy(&$val);
+        $(
+            {
+                $crate::irrelevant!($val, $reason, $($assumption)+);
+            }
+        )?
+        $val
+    }};
+}
+/// Implemented for all types with `()`, and for `Copy` ones with `u8` as well.
+///
+/// So, `A` can only be inferred for types which aren't `Copy`, which only have a single implementation.
+#[doc(hidden)]
+pub trait NotCopy<A> {}
+impl<T> NotCopy<()> for T {}
+impl<T: Copy> NotCopy<u8> for T {}
+/// Fails to compile for values which are `Copy`, since they could still be used after being moved out.
+#[doc(hidden)]
+pub fn __assert_not_copy<A, T: NotCopy<A>>(_value: &T) {}
@@ -0,0 +1,64 @@
+use crate::Location;
+use std::sync::Mutex;
+
+static TEMPORARY_IGNORES: Mutex<Vec<Location>> = Mutex::new(Vec::new());
+/// A version of [`irrelevant`](crate::irrelevant) for values that are only ignored *for now*.
+///
+/// Besides ignoring the value, it registers the place it was used in, when it runs for the first time.
+/// All the registered places can be listed using [`temporary_ignores`], which makes it easy to track the tech debt
+/// left behind, and to prioritize removing it.
+/// ```
+/// # use irrelevant::*;
+/// fn render(text: &str, style: &str) -> String {
+///     temp_irrelevant!(style, "Styles are not supported by the renderer yet.");
+///     text.to_owned()
+/// }
+/// render("Hello", "bold");
+/// assert_eq!(temporary_ignores().len(), 1);
+/// ```
+/// Just like [`irrelevant`](crate::irrelevant), it can also check assumptions about the value.
+/// ```
+/// # use irrelevant::*;
+/// # let style = "";
+/// temp_irrelevant!(style, "Styles are not supported by the renderer yet.", is_empty);
+/// ```
+#[macro_export]
+macro_rules! temp_irrelevant {
+    ($val:ident,$reason:literal) => {
+        $crate::__irrelevant_register_temporary!();
+        $crate::irrelevant!($val, $reason);
+    };
+    ($val:ident,$reason:literal,$($assumption:tt)+) => {
+        $crate::__irrelevant_register_temporary!();
+        $crate::irrelevant!($val, $reason, $($assumption)+);
+    };
+}
+/// Registers the place it is used in as a temporary ignore, once.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __irrelevant_register_temporary {
+    () => {{
+        static REGISTERED: ::std::sync::Once = ::std::sync::Once::new();
+        REGISTERED.call_once(|| {
+            $crate::__register_temporary($crate::Location {
+                file: file!(),
+                line: line!(),
+                column: column!(),
+            })
+        });
+    }};
+}
+#[doc(hidden)]
+pub fn __register_temporary(location: Location) {
+    TEMPORARY_IGNORES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .push(location);
+}
+/// Returns the places where [`temp_irrelevant`](crate::temp_irrelevant) ran so far, in the order they first ran in.
+pub fn temporary_ignores() -> Vec<Location> {
+    TEMPORARY_IGNORES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
@@ -0,0 +1,43 @@
+/// A version of [`irrelevant`](crate::irrelevant), which also emits a compiler warning as a reminder to revisit the
+/// ignored value.
+///
+/// The warning is a deprecation warning, with the reason as its note, so it shows up in the output of every build,
+/// and can be turned into an error with `#![deny(deprecated)]`, for example in CI.
+/// ```
+/// # use irrelevant::*;
+/// fn render(text: &str, style: &str) -> String {
+///     // warning: use of deprecated function `render::todo_irrelevant`: Styles are not supported yet.
+///     todo_irrelevant!(style, "Styles are not supported yet.");
+///     text.to_owned()
+/// }
+/// ```
+/// ```compile_fail
+/// #![deny(deprecated)]
+/// # use irrelevant::*;
+/// fn render(text: &str, style: &str) -> String {
+///     todo_irrelevant!(style, "Styles are not supported yet.");
+///     text.to_owned()
+/// }
+/// ```
+/// Just like [`irrelevant`](crate::irrelevant), it can also check assumptions about the value.
+#[macro_export]
+macro_rules! todo_irrelevant {
+    ($val:ident,$reason:literal) => {
+        $crate::__irrelevant_todo!($reason);
+        $crate::irrelevant!($val, $reason);
+    };
+    ($val:ident,$reason:literal,$($assumption:tt)+) => {
+        $crate::__irrelevant_todo!($reason);
+        $crate::irrelevant!($val, $reason, $($assumption)+);
+    };
+}
+/// Emits a deprecation warning with `$reason` as its note.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __irrelevant_todo {
+    ($reason:literal) => {{
+        #[deprecated(note = $reason)]
+        fn todo_irrelevant() {}
+        todo_irrelevant();
+    }};
+}
@@ -0,0 +1,89 @@
+use core::fmt;
+
+/// Describes an assumption violated in [`try_irrelevant`](crate::try_irrelevant), returned as an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IgnoreViolation {
+    /// The file the assumption was checked in, as returned by [`file`].
+    pub file: &'static str,
+    /// The line the assumption was checked at, as returned by [`line`].
+    pub line: u32,
+    /// The column the assumption was checked at, as returned by [`column`].
+    pub column: u32,
+    /// Why the value was ignored. For reasons with format arguments, this is the unformatted reason.
+    pub reason: &'static str,
+}
+impl fmt::Display for IgnoreViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { file, line, column, reason } = self;
+        write!(f, "[{file}:{line}:{column}] Assumption violated: {reason}")
+    }
+}
+impl core::error::Error for IgnoreViolation {}
+/// A version of [`irrelevant`](crate::irrelevant), which returns an error when an assumption is violated.
+///
+/// **Unlike the other variants, it returns early** from the enclosing function, with an [`IgnoreViolation`] converted
+/// into its error type with `From`, just like `?` would. So, it can only be used in functions returning a `Result`.
+/// The violation is still recorded (see [`last_violation`](crate::last_violation) and
+/// [`violation_count`](crate::violation_count)), but it is not reported to the violation handler, nor printed.
+/// ```
+/// # use irrelevant::*;
+/// fn parse(header: &[u8], padding: &[u8]) -> Result<usize, IgnoreViolation> {
+///     try_irrelevant!(padding, "Headers are never padded.", is_empty);
+///     Ok(header.len())
+/// }
+/// assert_eq!(parse(b"GET", b"").unwrap(), 3);
+/// # #[cfg(feature = "checks")]
+/// assert_eq!(parse(b"GET", b"  ").unwrap_err().reason, "Headers are never padded.");
+/// ```
+/// Besides that, it behaves exactly like [`irrelevant`](crate::irrelevant).
+#[macro_export]
+macro_rules! try_irrelevant {
+    // A value is ignored without any given reason.
+    ($val:ident) => {
+        $crate::__irrelevant_shadow!($val);
+    };
+    // A value is ignored without any additional assumption.
+    ($val:ident,$reason:literal) => {
+        //$reason
+        $crate::__irrelevant_shadow!($val);
+    };
+    // A value is ignored because of an assumption.
+    ($val:ident,$reason:literal,$($assumption:tt)+) => {
+        $crate::__irrelevant_reason_args!(result, $val, $reason, [] $($assumption)+);
+    };
+    // Several values are ignored at once, optionally because of assumptions about some of them.
+    ([$($val:ident $(=> $cond:expr)?),+ $(,)?],$reason:literal) => {
+        $($($crate::__irrelevant_check!(result, $reason, if $cond);)?)+
+        $($crate::__irrelevant_shadow!($val);)+
+    };
+    // Coordinates are ignored because of an assumption.
+    ([$($val:ident),+ $(,)?],$reason:literal,$($assumption:tt)+) => {
+        $crate::__irrelevant_assume!(result, [$($val),+], $reason, $($assumption)+);
+    };
+    // Fields of a struct are ignored, by destructuring it. They are checked together, as a part of the struct.
+    ($($struct:ident)::+ { $($pattern:tt)+ } = $val:expr,$reason:literal $(,$($assumption:tt)+)?) => {
+        $crate::__irrelevant_fields!(result, $($struct)::+ { $($pattern)+ }, $val, $reason $(,$($assumption)+)?);
+    };
+    // An expression is ignored. It is not a variable, so it can't be shadowed, and is only evaluated.
+    ($val:expr) => {
+        let _ = $val;
+    };
+    ($val:expr,$reason:literal) => {
+        //$reason
+        let _ = $val;
+    };
+    // A place, like a field of a struct, is ignored because of an assumption. It is checked where it is, so it is never
+    // moved out, which would fail if it is only borrowed.
+    ($base:ident $(.$field:tt)+,$reason:literal,$($assumption:tt)+) => {
+        $crate::__irrelevant_reason_args!(result, ($base $(.$field)+), $reason, [] $($assumption)+);
+    };
+    (*$val:ident,$reason:literal,$($assumption:tt)+) => {
+        $crate::__irrelevant_reason_args!(result, (*$val), $reason, [] $($assumption)+);
+    };
+    // An expression is ignored because of an assumption. Its value is moved into a temporary, which is checked and
+    // then dropped.
+    ($val:expr,$reason:literal,$($assumption:tt)+) => {{
+        let value = $val;
+        $crate::__irrelevant_reason_args!(result, value, $reason, [] $($assumption)+);
+    }};
+}
@@ -0,0 +1,357 @@
+#[cfg(feature = "alloc")]
+use crate::Location;
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+use core::fmt;
+#[cfg(feature = "std")]
+use core::sync::atomic::AtomicBool;
+use core::sync::atomic::{AtomicPtr, AtomicU64, Ordering};
+#[cfg(feature = "backtrace")]
+use std::backtrace::{Backtrace, BacktraceStatus};
+#[cfg(feature = "std")]
+use std::cell::RefCell;
+
+/// Describes a violated assumption. Requires the `alloc` feature.
+///
+/// The record owns its reason and details, so it can be kept around for as long as needed.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ViolationRecord {
+    /// Where the assumption was checked.
+    pub location: Location,
+    /// How serious the violation is, if a severity was given.
+    pub severity: Option<Severity>,
+    /// Why the value was ignored.
+    pub reason: String,
+    /// Describes how the assumption was violated, for the assumptions which can tell.
+    pub details: Option<String>,
+}
+#[cfg(feature = "alloc")]
+impl From<&ViolationInfo<'_>> for ViolationRecord {
+    fn from(violation: &ViolationInfo<'_>) -> Self {
+        let ViolationInfo { file, line, column, severity, reason, details, .. } = *violation;
+        Self {
+            location: Location { file, line, column },
+            severity,
+            reason: reason.to_string(),
+            details: details.map(|details| details.to_string()),
+        }
+    }
+}
+#[cfg(feature = "alloc")]
+impl fmt::Display for ViolationRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Location { file, line, column } = self.location;
+        let details = self.details.as_ref().map(|details| details as &dyn fmt::Display);
+        write_violation(f, file, line, column, self.severity, &self.reason, details)
+    }
+}
+/// How serious a violated assumption is, as selected with the `info:`, `warn:` or `error:` prefixes of
+/// [`irrelevant`](crate::irrelevant).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The violation is only worth noting.
+    Info,
+    /// The violation should be looked into.
+    Warning,
+    /// The violation is a serious error.
+    Error,
+}
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        })
+    }
+}
+/// Describes a violated assumption, as it is reported to the violation handler.
+#[derive(Debug, Clone, Copy)]
+pub struct ViolationInfo<'a> {
+    /// The file the assumption was checked in, as returned by [`file`].
+    pub file: &'static str,
+    /// The line the assumption was checked at, as returned by [`line`].
+    pub line: u32,
+    /// The column the assumption was checked at, as returned by [`column`].
+    pub column: u32,
+    /// How serious the violation is, if a severity was given.
+    pub severity: Option<Severity>,
+    /// Why the value was ignored, with its format arguments.
+    pub reason: fmt::Arguments<'a>,
+    /// Describes how the assumption was violated, for the assumptions which can tell.
+    pub details: Option<fmt::Arguments<'a>>,
+    /// Where the assumption was violated from. Requires the `backtrace` feature.
+    ///
+    /// It is captured with [`Backtrace::capture`], so it is only captured when enabled with the `RUST_BACKTRACE` or
+    /// `RUST_LIB_BACKTRACE` environment variables. Captured backtraces are printed to `stderr` after the message (or
+    /// logged as cargo warnings, with the `build-script` feature), but are not a part of its `Display` representation.
+    #[cfg(feature = "backtrace")]
+    pub backtrace: &'a Backtrace,
+}
+impl fmt::Display for ViolationInfo<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let details = self.details.as_ref().map(|details| details as &dyn fmt::Display);
+        write_violation(f, self.file, self.line, self.column, self.severity, &self.reason, details)
+    }
+}
+/// Writes the message describing a violated assumption, shared by [`ViolationInfo`] and [`ViolationRecord`].
+fn write_violation(
+    f: &mut fmt::Formatter<'_>,
+    file: &str,
+    line: u32,
+    column: u32,
+    severity: Option<Severity>,
+    reason: &dyn fmt::Display,
+    details: Option<&dyn fmt::Display>,
+) -> fmt::Result {
+    if let Some(severity) = severity {
+        write!(f, "{severity}: ")?;
+    }
+    write!(f, "[{file}:{line}:{column}] Assumption violated: {reason}")?;
+    if let Some(details) = details {
+        write!(f, " ({details})")?;
+    }
+    Ok(())
+}
+#[cfg(feature = "std")]
+std::thread_local! {
+    /// The violations captured by the innermost [`capture_violations`] running on this thread, if any.
+    static CAPTURED: RefCell<Option<Vec<ViolationRecord>>> = const { RefCell::new(None) };
+}
+/// Runs `f`, and returns records of the assumptions it violated on the current thread, in place of reporting them.
+///
+/// While `f` runs, the violations reported on the current thread are collected, instead of going to the violation
+/// handler or to `stderr`. Other threads are not affected, so tests running in parallel can check which assumptions
+/// they violated without racing on the global handler. Violations which panic, or are returned as errors by
+/// [`try_irrelevant`](crate::try_irrelevant), are not reported, so they are not captured either. Captures can be nested,
+/// with each violation captured by the innermost one.
+/// ```
+/// # use irrelevant::*;
+/// let violations = capture_violations(|| {
+///     let sauces = ["ketchup"];
+///     irrelevant!(sauces, "No sauces should come with a drink!", is_empty);
+/// });
+/// # #[cfg(feature = "checks")]
+/// assert_eq!(violations.len(), 1);
+/// # #[cfg(feature = "checks")]
+/// assert_eq!(violations[0].reason, "No sauces should come with a drink!");
+/// ```
+#[cfg(feature = "std")]
+pub fn capture_violations(f: impl FnOnce()) -> Vec<ViolationRecord> {
+    /// Restores the enclosing capture, even if `f` panics.
+    struct Restore(Option<Vec<ViolationRecord>>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CAPTURED.with(|captured| *captured.borrow_mut() = self.0.take());
+        }
+    }
+    let _restore = Restore(CAPTURED.with(|captured| captured.replace(Some(Vec::new()))));
+    f();
+    CAPTURED.with(|captured| captured.borrow_mut().take()).unwrap_or_default()
+}
+/// Adds a violated assumption to the capture running on the current thread, returning `false` if there is none.
+#[cfg(feature = "std")]
+fn capture_violation(violation: &ViolationInfo) -> bool {
+    CAPTURED.with(|captured| match &mut *captured.borrow_mut() {
+        Some(captured) => {
+            captured.push(violation.into());
+            true
+        }
+        None => false,
+    })
+}
+#[cfg(feature = "std")]
+std::thread_local! {
+    static LAST_VIOLATION: RefCell<Option<ViolationRecord>> = const { RefCell::new(None) };
+}
+/// The number of assumptions violated so far, in all the threads.
+static VIOLATION_COUNT: AtomicU64 = AtomicU64::new(0);
+/// Counts a violated assumption, keeps it as the last one, and appends it to the violation file.
+fn record_violation(violation: &ViolationInfo) {
+    VIOLATION_COUNT.fetch_add(1, Ordering::Relaxed);
+    #[cfg(feature = "std")]
+    {
+        let record = ViolationRecord::from(violation);
+        crate::log_file::log_violation(&record);
+        LAST_VIOLATION.with(|last| *last.borrow_mut() = Some(record));
+    }
+    #[cfg(not(feature = "std"))]
+    let _ = violation;
+}
+/// Returns the most recent assumption violated on the current thread, if any.
+///
+/// This is meant for inspecting what went wrong last, in a debugger or in a test.
+/// ```
+/// # use irrelevant::*;
+/// let sauces = ["ketchup"];
+/// irrelevant!(sauces, "No sauces should come with a drink!", is_empty);
+/// # #[cfg(feature = "checks")]
+/// let violation = last_violation().unwrap();
+/// # #[cfg(feature = "checks")]
+/// assert_eq!(violation.reason, "No sauces should come with a drink!");
+/// ```
+#[cfg(feature = "std")]
+pub fn last_violation() -> Option<ViolationRecord> {
+    LAST_VIOLATION.with(|last| last.borrow().clone())
+}
+/// Returns the number of assumptions violated so far, in all the threads, since the start of the program or the last
+/// call to [`reset_violation_count`].
+///
+/// Violations are counted by all the variants of [`irrelevant`](crate::irrelevant), including the ones which panic,
+/// regardless of where they are reported to. This allows checking that a piece of code violated no assumptions, for
+/// example in an integration test.
+/// ```
+/// # use irrelevant::*;
+/// reset_violation_count();
+/// let sauces: [&str; 0] = [];
+/// irrelevant!(sauces, "No sauces should come with a drink!", is_empty);
+/// assert_eq!(violation_count(), 0);
+/// ```
+pub fn violation_count() -> u64 {
+    VIOLATION_COUNT.load(Ordering::Relaxed)
+}
+/// Resets the number of violated assumptions returned by [`violation_count`] to zero.
+pub fn reset_violation_count() {
+    VIOLATION_COUNT.store(0, Ordering::Relaxed);
+}
+/// The handler set with [`set_violation_handler`], or null if there is none.
+static VIOLATION_HANDLER: AtomicPtr<()> = AtomicPtr::new(core::ptr::null_mut());
+/// Makes `handler` receive all the following violated assumptions, in place of printing them to `stderr`.
+///
+/// This allows forwarding them elsewhere, like to a logging framework. Without the `std` feature, there is no
+/// `stderr`, so violated assumptions are only reported if a handler is set. Assumptions violated in
+/// [`panic_irrelevant`](crate::panic_irrelevant) still panic, without calling the handler.
+/// ```
+/// # use irrelevant::*;
+/// fn to_log(violation: &ViolationInfo) {
+///     // log::warn!("{violation}");
+/// #   let _ = violation;
+/// }
+/// set_violation_handler(to_log);
+/// ```
+pub fn set_violation_handler(handler: fn(&ViolationInfo)) {
+    VIOLATION_HANDLER.store(handler as *mut (), Ordering::Release);
+}
+#[cfg(feature = "std")]
+static ABORT_ON_ERROR: AtomicBool = AtomicBool::new(false);
+/// Makes the process abort right after reporting an assumption violated with the `error` [`Severity`], if `abort` is
+/// `true`.
+///
+/// By default, such violations are only reported, just like the others. Aborting stops the process before the
+/// violation can cause more damage, while still reporting it to the violation handler (or to `stderr`) first.
+#[cfg(feature = "std")]
+pub fn set_abort_on_error(abort: bool) {
+    ABORT_ON_ERROR.store(abort, Ordering::Relaxed);
+}
+#[doc(hidden)]
+pub fn __report_violation(
+    file: &'static str,
+    line: u32,
+    column: u32,
+    severity: Option<Severity>,
+    reason: fmt::Arguments,
+    details: Option<fmt::Arguments>,
+) {
+    #[cfg(feature = "backtrace")]
+    let backtrace = Backtrace::capture();
+    let violation = &ViolationInfo {
+        file,
+        line,
+        column,
+        severity,
+        reason,
+        details,
+        #[cfg(feature = "backtrace")]
+        backtrace: &backtrace,
+    };
+    record_violation(violation);
+    #[cfg(feature = "std")]
+    let captured = capture_violation(violation);
+    #[cfg(not(feature = "std"))]
+    let captured = false;
+    // Captured violations are only reported to the capture.
+    if !captured {
+        let handler = VIOLATION_HANDLER.load(Ordering::Acquire);
+        if handler.is_null() {
+            log_violation(violation);
+        } else {
+            // SAFETY: Only `fn(&ViolationInfo)` pointers are ever stored in `VIOLATION_HANDLER`.
+            let handler = unsafe { core::mem::transmute::<*mut (), fn(&ViolationInfo)>(handler) };
+            handler(violation);
+        }
+    }
+    #[cfg(feature = "std")]
+    if severity == Some(Severity::Error) && ABORT_ON_ERROR.load(Ordering::Relaxed) {
+        std::process::abort();
+    }
+}
+/// Records a violated assumption, without reporting it.
+#[doc(hidden)]
+pub fn __record_violation(file: &'static str, line: u32, column: u32, reason: fmt::Arguments) {
+    record_violation(&ViolationInfo {
+        file,
+        line,
+        column,
+        severity: None,
+        reason,
+        details: None,
+        #[cfg(feature = "backtrace")]
+        backtrace: &Backtrace::disabled(),
+    });
+}
+/// Records a violated assumption, and panics with its message.
+///
+/// No backtrace is captured, since the panic hook prints the backtrace of the panic.
+#[doc(hidden)]
+#[track_caller]
+pub fn __panic_violation(
+    file: &'static str,
+    line: u32,
+    column: u32,
+    reason: fmt::Arguments,
+    details: Option<fmt::Arguments>,
+) -> ! {
+    let violation = &ViolationInfo {
+        file,
+        line,
+        column,
+        severity: None,
+        reason,
+        details,
+        #[cfg(feature = "backtrace")]
+        backtrace: &Backtrace::disabled(),
+    };
+    record_violation(violation);
+    panic!("{violation}")
+}
+/// Logs a violated assumption to `stderr`.
+#[cfg(all(feature = "std", not(feature = "build-script")))]
+fn log_violation(violation: &ViolationInfo) {
+    #[cfg(feature = "backtrace")]
+    if violation.backtrace.status() == BacktraceStatus::Captured {
+        eprintln!("{violation}\n{}", violation.backtrace);
+        return;
+    }
+    eprintln!("{violation}");
+}
+/// Logs a violated assumption as cargo warnings, for use in build scripts. Captured backtraces are logged after it.
+///
+/// Cargo only reads the rest of the line after `cargo:warning=`, so each line of the message is a warning of its own.
+#[cfg(feature = "build-script")]
+fn log_violation(violation: &ViolationInfo) {
+    for line in violation.to_string().lines() {
+        println!("cargo:warning={line}");
+    }
+    #[cfg(feature = "backtrace")]
+    if violation.backtrace.status() == BacktraceStatus::Captured {
+        for line in violation.backtrace.to_string().lines() {
+            println!("cargo:warning={line}");
+        }
+    }
+}
+/// Without `std`, there is nowhere to log violated assumptions to, so they are only reported to the handler.
+#[cfg(not(feature = "std"))]
+fn log_violation(violation: &ViolationInfo) {
+    let _ = violation;
+}
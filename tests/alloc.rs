@@ -0,0 +1,41 @@
+#![cfg(feature = "alloc-counter")]
+use irrelevant::*;
+use std::sync::{Arc, Barrier};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator::new();
+
+#[test]
+fn no_allocations() {
+    let marker = AllocMarker::now();
+    let sum: u32 = [1, 2, 3].iter().sum();
+    panic_irrelevant!(sum, "The sum is only computed to warm up the cache.", no_alloc_since(marker));
+}
+#[test]
+fn allocations_on_other_threads() {
+    // Spawning a thread allocates, so it is spawned before the marker, and only allocates after it.
+    let barrier = Arc::new(Barrier::new(2));
+    let thread = std::thread::spawn({
+        let barrier = Arc::clone(&barrier);
+        move || {
+            barrier.wait();
+            let values = Vec::from([1, 2, 3]);
+            barrier.wait();
+            values.len()
+        }
+    });
+    let marker = AllocMarker::now();
+    barrier.wait();
+    barrier.wait();
+    let done = ();
+    panic_irrelevant!(done, "Only other threads allocate.", no_alloc_since(marker));
+    assert_eq!(thread.join().unwrap(), 3);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: The sum is computed without allocating. (1 allocations were made since the marker)")]
+fn allocations() {
+    let marker = AllocMarker::now();
+    let sum: u32 = Vec::from([1, 2, 3]).iter().sum();
+    panic_irrelevant!(sum, "The sum is computed without allocating.", no_alloc_since(marker));
+}
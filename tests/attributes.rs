@@ -0,0 +1,51 @@
+#![cfg(all(feature = "attributes", feature = "std"))]
+use irrelevant::*;
+use std::collections::HashMap;
+
+struct Context {
+    admin: bool,
+}
+#[irrelevant_params]
+fn add(a: u32, b: u32, #[irrelevant("Adding numbers does not require any privileges.")] context: &Context) -> u32 {
+    a + b
+}
+#[irrelevant_params]
+fn lookup<'a, K: std::hash::Hash + Eq, F: Fn(&K) -> bool>(
+    map: &'a HashMap<K, u32>,
+    key: &K,
+    #[irrelevant] filter: F,
+    #[irrelevant(reason = "Lookups are never cached.", assume = is_empty)] cache: Vec<u32>,
+) -> Option<&'a u32> {
+    map.get(key)
+}
+#[irrelevant_params]
+fn delete(path: &str, #[irrelevant("Only admins can reach this.", assume = context.admin)] context: &Context) -> usize {
+    path.len()
+}
+mod renamed {
+    use irrelevant as ignoring;
+
+    #[ignoring::irrelevant_params(crate = ignoring)]
+    pub fn sub(a: u32, b: u32, #[irrelevant("Nothing wraps around.", assume = a >= b)] scale: u32) -> u32 {
+        a - b
+    }
+}
+#[test]
+fn parameters_are_ignored() {
+    assert_eq!(add(1, 2, &Context { admin: false }), 3);
+    let map = HashMap::from([("a", 1)]);
+    assert_eq!(lookup(&map, &"a", |_| true, Vec::new()), Some(&1));
+    assert_eq!(last_violation(), None);
+}
+#[test]
+fn assumptions_are_checked() {
+    assert_eq!(delete("/tmp", &Context { admin: true }), 4);
+    assert_eq!(last_violation(), None);
+    assert_eq!(delete("/tmp", &Context { admin: false }), 4);
+    assert_eq!(last_violation().unwrap().reason, "Only admins can reach this.");
+}
+#[test]
+fn renamed_crate() {
+    assert_eq!(renamed::sub(3, 1, 1), 2);
+    assert_eq!(last_violation(), None);
+}
@@ -0,0 +1,25 @@
+#![cfg(feature = "backtrace")]
+use irrelevant::*;
+use std::backtrace::BacktraceStatus;
+use std::sync::Mutex;
+
+static HANDLED: Mutex<Vec<(String, BacktraceStatus, String)>> = Mutex::new(Vec::new());
+fn collect(violation: &ViolationInfo) {
+    let backtrace = violation.backtrace;
+    HANDLED.lock().unwrap().push((violation.to_string(), backtrace.status(), backtrace.to_string()));
+}
+fn violate() {
+    let sauces = ["ketchup"];
+    irrelevant!(sauces, "No sauces should come with a drink!", is_empty);
+}
+// Backtraces are only captured when enabled by the environment, so it is set before the first violation.
+#[test]
+fn violations_capture_backtraces() {
+    std::env::set_var("RUST_LIB_BACKTRACE", "1");
+    set_violation_handler(collect);
+    violate();
+    let (message, status, backtrace) = HANDLED.lock().unwrap().pop().unwrap();
+    assert_eq!(message, "[tests/backtrace.rs:13:5] Assumption violated: No sauces should come with a drink!");
+    assert_eq!(status, BacktraceStatus::Captured);
+    assert!(backtrace.contains("violate"));
+}
@@ -0,0 +1,34 @@
+use irrelevant::*;
+
+#[test]
+fn baseline_matches() {
+    register_baseline!("locale", String::from("en-US"));
+    let locale = String::from("en-US");
+    panic_irrelevant!(locale, "The locale was already applied at startup.", matches_baseline("locale"));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(
+    expected = "Assumption violated: The thread count was already applied at startup. (8 diverged from the \"threads\" baseline 4, registered at tests/baseline.rs:16:5)"
+)]
+fn baseline_diverges() {
+    let threads: usize = 4;
+    register_baseline!("threads", threads);
+    let threads: usize = 8;
+    panic_irrelevant!(threads, "The thread count was already applied at startup.", matches_baseline("threads"));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "(the \"port\" baseline registered at tests/baseline.rs:24:5 has a different type)")]
+fn baseline_type_differs() {
+    register_baseline!("port", 8080u16);
+    let port: u32 = 8080;
+    panic_irrelevant!(port, "The port was already bound at startup.", matches_baseline("port"));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "(no \"timezone\" baseline is registered)")]
+fn baseline_missing() {
+    let timezone = "UTC";
+    panic_irrelevant!(timezone, "The timezone was already applied at startup.", matches_baseline("timezone"));
+}
@@ -0,0 +1,137 @@
+use irrelevant::*;
+
+struct Order {
+    sauces: Vec<String>,
+    note: String,
+    ice: Box<u32>,
+}
+impl Order {
+    /// Ignores the fields of a borrowed order, which can't be moved out of it.
+    fn serve(&mut self) -> usize {
+        irrelevant!(self.note, "Notes are only read by the cook.", is_empty);
+        panic_irrelevant!(self.note, "Notes are only read by the cook.", == "");
+        debug_irrelevant!(self.note, "Notes are only read by the cook.", std::string::String);
+        irrelevant!(self.ice, "Hot drinks come without ice.", *self.ice == 0);
+        irrelevant!(error: self.sauces, "No sauces should come with a drink!", is_empty);
+        // The fields are still usable after being ignored.
+        self.sauces.push("ketchup".into());
+        self.sauces.len()
+    }
+}
+fn serve(order: &mut Order) -> Result<usize, IgnoreViolation> {
+    try_irrelevant!(order.ice, "Hot drinks come without ice.", *order.ice == 0);
+    try_irrelevant!(order.note, "Notes are only read by the cook.", is_empty);
+    Ok(order.serve())
+}
+#[test]
+fn borrowed_fields() {
+    let mut order = Order { sauces: Vec::new(), note: String::new(), ice: Box::new(0) };
+    assert_eq!(serve(&mut order).unwrap(), 1);
+    assert_eq!(order.sauces, ["ketchup"]);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Notes are only read by the cook. (\"extra hot \" is not trimmed)")]
+fn borrowed_field_violated() {
+    let order = &Order { sauces: Vec::new(), note: "extra hot ".into(), ice: Box::new(0) };
+    panic_irrelevant!(order.note, "Notes are only read by the cook.", trimmed);
+}
+#[test]
+fn mutable_references() {
+    let mut sauces = vec![String::from("ketchup")];
+    let added = &mut sauces;
+    irrelevant!(added, "Sauces are added by the waiter.", added.len() == 1);
+    let added = &mut sauces;
+    panic_irrelevant!(added, "Sauces are added by the waiter.", *added == ["ketchup"]);
+    let added = &mut sauces;
+    irrelevant!(added, "Sauces are added by the waiter.", &mut Vec<String>);
+    let added = &mut sauces;
+    irrelevant!(added, "Sauces are added by the waiter.", drained);
+    let added = &mut sauces;
+    irrelevant!(*added, "Sauces are added by the waiter.", contains(&String::from("ketchup")));
+    // The references were only ignored, so the vector can be borrowed again.
+    sauces.push("mustard".into());
+    assert_eq!(sauces.len(), 2);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Sauces are added by the waiter. (1 entries remain)")]
+fn mutable_reference_violated() {
+    let mut sauces = vec![String::from("ketchup")];
+    let added = &mut sauces;
+    panic_irrelevant!(added, "Sauces are added by the waiter.", drained);
+}
+#[test]
+fn boxes() {
+    let ice = Box::new(0u32);
+    irrelevant!(ice, "Hot drinks come without ice.", *ice == 0);
+    let ice = Box::new(0u32);
+    irrelevant!(ice, "Hot drinks come without ice.", Box<u32>);
+    let ice = Box::new(0u32);
+    irrelevant!(ice, "Hot drinks come without ice.", == Box::new(0));
+    let ice = Box::new(0u32);
+    irrelevant!(*ice, "Hot drinks come without ice.", == 0);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Hot drinks come with at most 1 cube. (2 is larger than 1)")]
+fn box_violated() {
+    let ice = Box::new(2u32);
+    panic_irrelevant!(*ice, "Hot drinks come with at most 1 cube.", <= 1);
+}
+#[test]
+fn strings() {
+    let note = String::new();
+    irrelevant!(note, "Notes are only read by the cook.", is_empty);
+    let note = String::new();
+    irrelevant!(note, "Notes are only read by the cook.", == "");
+    let note = String::new();
+    irrelevant!(note, "Notes are only read by the cook.", std::string::String);
+    let note = String::new();
+    irrelevant!(note, "Notes are only read by the cook.", trimmed);
+    let note = String::new();
+    irrelevant!(note, "Notes are only read by the cook.", note.len() < 3);
+    let note = String::new();
+    irrelevant_take!(let taken = note, "Notes are only read by the cook.", is_empty);
+    assert!(taken.is_empty());
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Notes are only read by the cook. (\" extra hot\" is not trimmed)")]
+fn string_violated() {
+    let note = String::from(" extra hot");
+    panic_irrelevant!(note, "Notes are only read by the cook.", trimmed);
+}
+#[test]
+fn destructured_fields() {
+    let mut order = Order { sauces: Vec::new(), note: String::new(), ice: Box::new(0) };
+    irrelevant!(Order { note, ice, .. } = order, "Notes and ice are handled by the bar.");
+    panic_irrelevant!(Order { note, ice, .. } = order, "Hot drinks come without ice.", *order.ice == 0);
+    debug_irrelevant!(self::Order { sauces, note, ice } = order, "Drinks are served as ordered.", order.note.is_empty());
+    irrelevant!(warn: Order { note, .. } = &order, "Notes are only read by the cook.", order.note.len() < 80);
+    let borrowed = &mut order;
+    irrelevant!(Order { sauces, .. } = *borrowed, "No sauces should come with a drink!", borrowed.sauces.is_empty());
+    // The fields were only borrowed, so the order is still usable.
+    order.sauces.push("ketchup".into());
+    assert_eq!(order.sauces.len(), 1);
+}
+#[cfg(feature = "checks")]
+fn serve_drink(order: &Order) -> Result<u32, IgnoreViolation> {
+    try_irrelevant!(Order { note, sauces, .. } = order, "Drinks are served plain.", order.sauces.is_empty());
+    Ok(*order.ice)
+}
+#[cfg(feature = "checks")]
+#[test]
+fn destructured_fields_result() {
+    let order = Order { sauces: Vec::new(), note: String::new(), ice: Box::new(1) };
+    assert_eq!(serve_drink(&order).unwrap(), 1);
+    let order = Order { sauces: vec!["mustard".into()], note: String::new(), ice: Box::new(1) };
+    assert_eq!(serve_drink(&order).unwrap_err().reason, "Drinks are served plain.");
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Hot drinks come without ice.")]
+fn destructured_fields_violated() {
+    let order = Order { sauces: Vec::new(), note: String::new(), ice: Box::new(2) };
+    panic_irrelevant!(Order { note, ice, .. } = order, "Hot drinks come without ice.", *order.ice == 0);
+}
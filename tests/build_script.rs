@@ -0,0 +1,68 @@
+#![cfg(feature = "build-script")]
+use irrelevant::*;
+use std::process::Command;
+
+const MOCK_ENV: &str = "IRRELEVANT_MOCK_BUILD_SCRIPT";
+/// Acts like a build script violating an assumption, when run by [`violations_are_cargo_warnings`].
+#[test]
+fn mock_build_script() {
+    if std::env::var_os(MOCK_ENV).is_none() {
+        return;
+    }
+    let target = "wasm32-unknown-unknown";
+    irrelevant!(target, "Build scripts only run for native targets.", !target.starts_with("wasm"));
+}
+/// Acts like a build script violating an assumption with a multi-line reason.
+#[test]
+fn mock_build_script_multiline() {
+    if std::env::var_os(MOCK_ENV).is_none() {
+        return;
+    }
+    let target = "wasm32-unknown-unknown";
+    irrelevant!(target, "Build scripts only run for native targets.\nWasm is built by a separate step.", !target.starts_with("wasm"));
+}
+/// Runs one of the mock build scripts, and returns what it printed to `stdout`. Backtraces are only captured if
+/// `backtrace` is set, and the `backtrace` feature is enabled.
+fn run_mock(name: &str, backtrace: bool) -> String {
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", name, "--nocapture", "--test-threads=1"])
+        .env(MOCK_ENV, "1")
+        .env("RUST_LIB_BACKTRACE", if backtrace { "1" } else { "0" })
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+#[test]
+fn violations_are_cargo_warnings() {
+    let stdout = run_mock("mock_build_script", false);
+    // The test harness prints the name of the test on the same line, before the warning.
+    let warning = stdout
+        .lines()
+        .find_map(|line| line.find("cargo:warning=").map(|start| &line[start..]))
+        .expect("no cargo warning was printed");
+    assert!(warning.starts_with("cargo:warning=[tests/build_script.rs:13:5] Assumption violated: "));
+    assert!(warning.ends_with("Build scripts only run for native targets."));
+}
+#[test]
+fn multiline_violations_are_split() {
+    let stdout = run_mock("mock_build_script_multiline", false);
+    let warnings: Vec<_> = stdout
+        .lines()
+        .filter_map(|line| line.find("cargo:warning=").map(|start| &line[start..]))
+        .collect();
+    assert_eq!(warnings.len(), 2, "{stdout}");
+    assert!(warnings[0].ends_with("Build scripts only run for native targets."));
+    assert_eq!(warnings[1], "cargo:warning=Wasm is built by a separate step.");
+}
+#[cfg(feature = "backtrace")]
+#[test]
+fn backtraces_are_cargo_warnings() {
+    let stdout = run_mock("mock_build_script", true);
+    let warnings: Vec<_> = stdout
+        .lines()
+        .filter_map(|line| line.find("cargo:warning=").map(|start| &line[start..]))
+        .collect();
+    assert!(warnings[0].ends_with("Build scripts only run for native targets."));
+    assert!(warnings[1..].iter().any(|warning| warning.contains("mock_build_script")), "{stdout}");
+}
@@ -0,0 +1,110 @@
+use irrelevant::*;
+
+const MAGIC: u32 = 0xCAFE_BABE;
+
+#[test]
+fn big_endian_matches() {
+    let header = [0xCA, 0xFE, 0xBA, 0xBE, 0x00, 0x34];
+    panic_irrelevant!(header, "Class files start with the magic number.", be_u32 == MAGIC);
+    let header = vec![0xBE, 0xBA, 0xFE, 0xCA];
+    panic_irrelevant!(header, "The header is written on a little-endian machine.", le_u32 == MAGIC);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Class files start with the magic number. (read 0xbebafeca, expected 0xcafebabe)")]
+fn wrong_endianness() {
+    let header = [0xBE, 0xBA, 0xFE, 0xCA];
+    panic_irrelevant!(header, "Class files start with the magic number.", be_u32 == MAGIC);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Class files start with the magic number. (fewer than 4 bytes, expected 0xcafebabe)")]
+fn too_short() {
+    let header: &[u8] = &[0xCA, 0xFE];
+    panic_irrelevant!(header, "Class files start with the magic number.", be_u32 == MAGIC);
+}
+#[test]
+fn crc32_known_values() {
+    assert_eq!(checks::crc32(b""), 0);
+    assert_eq!(checks::crc32("The quick brown fox jumps over the lazy dog"), 0x414F_A339);
+    let data = *b"123456789";
+    panic_irrelevant!(data, "The firmware image is verified by the bootloader.", crc32 == 0xCBF4_3926);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: The firmware image is verified by the bootloader. (the checksum is 0xcbf43926)")]
+fn crc32_mismatch() {
+    let data = b"123456789".to_vec();
+    panic_irrelevant!(data, "The firmware image is verified by the bootloader.", crc32 == 0x1234_5678);
+}
+#[test]
+fn popcount_integers() {
+    let pending = 0u32;
+    panic_irrelevant!(pending, "Every request was answered.", popcount == 0);
+    let flags = 0b1011_0000u8;
+    panic_irrelevant!(flags, "Exactly three features are enabled.", popcount == 3);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Every request was answered. (2 bits are set)")]
+fn popcount_integer_mismatch() {
+    let pending = 0b1001u64;
+    panic_irrelevant!(pending, "Every request was answered.", popcount == 0);
+}
+#[test]
+fn popcount_arrays() {
+    let pages = [0u64, 1 << 63, 0b11];
+    panic_irrelevant!(pages, "Three pages are mapped.", popcount == 3);
+    let backing = vec![u64::MAX; 2];
+    let pages: &[u64] = &backing;
+    panic_irrelevant!(pages, "Every page is mapped.", popcount == 128);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: No pages are mapped. (1 bits are set)")]
+fn popcount_array_mismatch() {
+    let backing = vec![0u64, 0, 1 << 20];
+    let pages = backing.as_slice();
+    panic_irrelevant!(pages, "No pages are mapped.", popcount == 0);
+}
+#[test]
+fn clean_buffers() {
+    let name = b"config.toml";
+    panic_irrelevant!(name, "Names are passed on as C strings.", no_byte(0));
+    let name = String::from("config.toml");
+    panic_irrelevant!(name, "Names are passed on as C strings.", no_byte(0));
+    let header = vec![0u8, 1, 2];
+    panic_irrelevant!(header, "Headers are never terminated by newlines.", no_byte(b'\n'));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Names are passed on as C strings. (found the byte 0x00 at 6)")]
+fn contaminated_buffer() {
+    let name = b"config\0.toml";
+    panic_irrelevant!(name, "Names are passed on as C strings.", no_byte(0));
+}
+#[test]
+fn bit_patterns() {
+    let mask: u8 = 0xff;
+    panic_irrelevant!(mask, "Every interrupt is masked during boot.", all_ones);
+    let status: u32 = u32::MAX;
+    panic_irrelevant!(status, "The bus floats high when nothing is attached.", all_ones);
+    let errors: u8 = 0;
+    panic_irrelevant!(errors, "The controller was just reset.", all_zeros);
+    let status: u32 = 0;
+    panic_irrelevant!(status, "The device is idle.", all_zeros);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Every interrupt is masked during boot. (0x7f is not all ones)")]
+fn u8_not_all_ones() {
+    let mask: u8 = 0x7f;
+    panic_irrelevant!(mask, "Every interrupt is masked during boot.", all_ones);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: The device is idle. (0x00000400 is not all zeros)")]
+fn u32_not_all_zeros() {
+    let status: u32 = 0x400;
+    panic_irrelevant!(status, "The device is idle.", all_zeros);
+}
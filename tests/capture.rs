@@ -0,0 +1,80 @@
+#![cfg(feature = "std")]
+use irrelevant::*;
+
+#[cfg(feature = "checks")]
+#[test]
+fn captures_violations() {
+    let violations = capture_violations(|| {
+        let sauces: [&str; 0] = [];
+        irrelevant!(sauces, "No sauces should come with a drink!", is_empty);
+        let sauces = ["ketchup"];
+        irrelevant!(sauces, "No sauces should come with a drink!", is_empty);
+        let ice = 3;
+        irrelevant!(error: ice, "Hot drinks come with at most {} cubes.", 2; ice <= 2);
+        let port = 80;
+        irrelevant!(port, "Only unprivileged ports are used.", > 1023);
+    });
+    let messages: Vec<String> = violations.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        messages,
+        [
+            "[tests/capture.rs:11:9] Assumption violated: No sauces should come with a drink!",
+            "error: [tests/capture.rs:13:9] Assumption violated: Hot drinks come with at most 2 cubes.",
+            "[tests/capture.rs:15:9] Assumption violated: Only unprivileged ports are used. (80 is not larger than 1023)",
+        ]
+    );
+    assert_eq!(violations[1].severity, Some(Severity::Error));
+    assert_eq!(violations[2].reason, "Only unprivileged ports are used.");
+    assert_eq!(violations[2].details.as_deref(), Some("80 is not larger than 1023"));
+}
+#[test]
+fn captures_nothing() {
+    let violations = capture_violations(|| {
+        let sauces: [&str; 0] = [];
+        irrelevant!(sauces, "No sauces should come with a drink!", is_empty);
+    });
+    assert!(violations.is_empty());
+}
+#[cfg(feature = "checks")]
+#[test]
+fn nested_captures() {
+    let mut inner = Vec::new();
+    let outer = capture_violations(|| {
+        let sauces = ["ketchup"];
+        irrelevant!(sauces, "No sauces should come with a drink!", is_empty);
+        inner = capture_violations(|| {
+            let ice = 3;
+            irrelevant!(ice, "Hot drinks come without ice.", ice == 0);
+        });
+        let straws = 2;
+        irrelevant!(straws, "Drinks come with a single straw.", straws == 1);
+    });
+    let reasons: Vec<&str> = outer.iter().map(|violation| violation.reason.as_str()).collect();
+    assert_eq!(reasons, ["No sauces should come with a drink!", "Drinks come with a single straw."]);
+    assert_eq!(inner.len(), 1);
+    assert_eq!(inner[0].reason, "Hot drinks come without ice.");
+}
+#[test]
+fn other_threads_are_not_captured() {
+    let violations = capture_violations(|| {
+        std::thread::spawn(|| {
+            let sauces = ["ketchup"];
+            irrelevant!(sauces, "No sauces should come with a drink!", is_empty);
+        })
+        .join()
+        .unwrap();
+    });
+    assert!(violations.is_empty());
+}
+#[cfg(feature = "checks")]
+#[test]
+fn capture_ends_on_panic() {
+    let violations = capture_violations(|| {
+        let result = std::panic::catch_unwind(|| capture_violations(|| panic!("the drink was spilled")));
+        assert!(result.is_err());
+        // The inner capture has ended, so this violation goes to the outer one.
+        let sauces = ["ketchup"];
+        irrelevant!(sauces, "No sauces should come with a drink!", is_empty);
+    });
+    assert_eq!(violations.len(), 1);
+}
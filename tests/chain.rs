@@ -0,0 +1,37 @@
+use irrelevant::*;
+
+fn handle(request: &str, session: u64, locale: &str, trace_id: u128) -> usize {
+    irrelevant_chain!(
+        session => "Anonymous requests are also handled.",
+        locale => "The response is not localized.",
+        trace_id => "Tracing is done by the proxy."
+    );
+    let _: [ExplicitlyIgnoredValue; 3] = [session, locale, trace_id];
+    request.len()
+}
+#[test]
+fn chain_ignores_every_value() {
+    assert_eq!(handle("GET /", 7, "pl", 0), 5);
+}
+fn render(text: &str, style: &str, width: usize, cache: &[u8]) -> String {
+    irrelevant!([style, width, cache], "Text is rendered plainly, for now.");
+    let _: [ExplicitlyIgnoredValue; 3] = [style, width, cache];
+    text.to_owned()
+}
+#[test]
+fn list_ignores_every_value() {
+    assert_eq!(render("Hello", "bold", 80, &[]), "Hello");
+}
+#[test]
+fn list_conditions_hold() {
+    let (sauces, ice, straw) = ([(); 0], 0, true);
+    panic_irrelevant!([sauces => sauces.is_empty(), ice => ice == 0, straw,], "Hot drinks come plain.");
+    let _: [ExplicitlyIgnoredValue; 3] = [sauces, ice, straw];
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Hot drinks come plain.")]
+fn list_condition_violated() {
+    let (sauces, ice, straw) = ([(); 0], 2, true);
+    panic_irrelevant!([sauces => sauces.is_empty(), straw, ice => ice == 0], "Hot drinks come plain.");
+}
@@ -0,0 +1,71 @@
+#![cfg(all(feature = "checks", feature = "std"))]
+use irrelevant::*;
+use std::sync::{Arc, Barrier};
+use std::thread;
+
+/// Ignores a timestamp, which is only used to order the log entries of all the threads.
+fn log_entry(timestamp: u64) {
+    irrelevant!(timestamp, "Log entries are timestamped by a monotonic clock.", after_last_global);
+}
+/// Ignores a timestamp taken from the same clock, when the log is flushed.
+fn flush(timestamp: u64) {
+    irrelevant!(timestamp, "The log is flushed after its entries are written.", after_last_global);
+}
+// The latest timestamp is shared by the whole program, so all the scenarios share a single test.
+#[test]
+fn timestamps() {
+    let threads = 4;
+    let barrier = Arc::new(Barrier::new(threads));
+    let handles: Vec<_> = (0..threads as u64)
+        .map(|thread| {
+            let barrier = Arc::clone(&barrier);
+            thread::spawn(move || {
+                barrier.wait();
+                // Each thread reads its own timestamps, all of them after the last one read before it.
+                let violations = capture_violations(|| {
+                    for i in 0..1000 {
+                        log_entry(i * threads as u64 + thread + 1);
+                    }
+                });
+                for violation in violations {
+                    assert!(violation.details.unwrap().contains("is before the last timestamp"));
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    // The threads race, so some of their timestamps may go back, but the latest one is always kept.
+    assert!(capture_violations(|| {
+        log_entry(4000);
+        log_entry(4000);
+        log_entry(4001);
+    })
+    .is_empty());
+    let violations = capture_violations(|| log_entry(3999));
+    assert_eq!(violations[0].reason, "Log entries are timestamped by a monotonic clock.");
+    assert_eq!(violations[0].details.as_deref(), Some("3999 is before the last timestamp, 4001"));
+    // The regressing timestamp is not kept.
+    assert!(capture_violations(|| log_entry(4001)).is_empty());
+
+    // Timestamps ignored at different places are checked against each other.
+    assert!(capture_violations(|| {
+        log_entry(5000);
+        flush(5010);
+        log_entry(5020);
+    })
+    .is_empty());
+    let violations = capture_violations(|| flush(5015));
+    assert_eq!(violations[0].reason, "The log is flushed after its entries are written.");
+    assert_eq!(violations[0].details.as_deref(), Some("5015 is before the last timestamp, 5020"));
+
+    // Regressions are reported once, and the timestamps after them are checked against the latest one.
+    let violations = capture_violations(|| {
+        for timestamp in [6010u64, 6020, 6015, 6030, 6025, 6040] {
+            irrelevant!(timestamp, "Timestamps only go forward.", after_last_global);
+        }
+    });
+    let details: Vec<_> = violations.iter().map(|violation| violation.details.as_deref().unwrap()).collect();
+    assert_eq!(details, ["6015 is before the last timestamp, 6020", "6025 is before the last timestamp, 6030"]);
+}
@@ -0,0 +1,131 @@
+use irrelevant::*;
+use std::collections::{BTreeMap, HashMap};
+
+#[test]
+fn drained_empty() {
+    let mut map = HashMap::from([(1, "one"), (2, "two")]);
+    for _ in map.drain() {}
+    panic_irrelevant!(map, "Every entry was processed above.", drained);
+    let queue: Vec<u8> = Vec::new();
+    panic_irrelevant!(queue, "Every entry was processed above.", drained);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Every entry was processed above. (2 entries remain)")]
+fn drained_non_empty() {
+    let map = HashMap::from([(1, "one"), (2, "two")]);
+    panic_irrelevant!(map, "Every entry was processed above.", drained);
+}
+#[test]
+fn contains_present() {
+    let terminated = [1, 2, 3, 0];
+    panic_irrelevant!(terminated, "The buffer always ends with the sentinel.", contains(&0));
+    let terminated = vec![1, 2, 3, 0];
+    panic_irrelevant!(terminated, "The buffer always ends with the sentinel.", contains(&0));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: The buffer always ends with the sentinel.")]
+fn contains_absent_slice() {
+    let terminated: &[u8] = &[1, 2, 3];
+    panic_irrelevant!(terminated, "The buffer always ends with the sentinel.", contains(&0));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: The buffer always ends with the sentinel.")]
+fn contains_absent_vec() {
+    let terminated = vec![1, 2, 3];
+    panic_irrelevant!(terminated, "The buffer always ends with the sentinel.", contains(&0));
+}
+#[test]
+fn extreme_keys_match() {
+    let versions = BTreeMap::from([(1, "initial"), (2, "fix"), (7, "latest")]);
+    panic_irrelevant!(versions, "Version history starts at 1.", min_key == 1);
+    let versions = BTreeMap::from([(1, "initial"), (2, "fix"), (7, "latest")]);
+    panic_irrelevant!(versions, "Version 7 is the latest one.", max_key == 7);
+}
+#[cfg(all(feature = "checks", not(feature = "pretty")))]
+#[test]
+#[should_panic(expected = "Assumption violated: Version history starts at 1. (got Some(2), expected Some(1))")]
+fn min_key_mismatch() {
+    let versions = BTreeMap::from([(2, "fix"), (7, "latest")]);
+    panic_irrelevant!(versions, "Version history starts at 1.", min_key == 1);
+}
+#[cfg(all(feature = "checks", not(feature = "pretty")))]
+#[test]
+#[should_panic(expected = "Assumption violated: Version 7 is the latest one. (got None, expected Some(7))")]
+fn max_key_empty() {
+    let versions = BTreeMap::<u32, &str>::new();
+    panic_irrelevant!(versions, "Version 7 is the latest one.", max_key == 7);
+}
+#[cfg(feature = "pretty")]
+#[test]
+fn min_key_mismatch_diff() {
+    let result = std::panic::catch_unwind(|| {
+        let versions = BTreeMap::from([((1, 2), "fix"), ((2, 0), "latest")]);
+        panic_irrelevant!(versions, "Version history starts at 1.0.", min_key == (1, 0));
+    });
+    let message = *result.unwrap_err().downcast::<String>().unwrap();
+    assert!(
+        message.contains(concat!(
+            "the values differ:\n",
+            "  Some(\n",
+            "      (\n",
+            "          1,\n",
+            "\x1b[31m-         0,\x1b[0m\n",
+            "\x1b[32m+         2,\x1b[0m\n",
+            "      ),\n",
+            "  )\n",
+        )),
+        "{message}"
+    );
+}
+#[test]
+fn all_unique_holds() {
+    let ids = vec![3, 1, 2];
+    panic_irrelevant!(ids, "Ids are deduplicated on insertion.", all_unique);
+    let names = ["tea", "coffee"];
+    panic_irrelevant!(names, "Names are deduplicated on insertion.", all_unique);
+    let empty: Vec<u8> = Vec::new();
+    panic_irrelevant!(empty, "Ids are deduplicated on insertion.", all_unique);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Names are deduplicated on insertion. (\"coffee\" appears more than once)")]
+fn all_unique_with_duplicates() {
+    let names = ["tea", "coffee", "juice", "coffee", "tea", "tea"];
+    panic_irrelevant!(names, "Names are deduplicated on insertion.", all_unique);
+}
+#[cfg(feature = "checks")]
+#[test]
+fn count_matches() {
+    let coordinates = "1,2,3".split(',');
+    panic_irrelevant!(coordinates, "Points are always 3D.", count == 3);
+    let mut consumed = 0;
+    let items = [1, 2].iter().inspect(|_| consumed += 1);
+    panic_irrelevant!(items, "Pairs have two items.", count == 2);
+    assert_eq!(consumed, 2);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Points are always 3D. (yielded 2 items, expected 3)")]
+fn count_mismatch() {
+    let coordinates = "1,2".split(',');
+    panic_irrelevant!(coordinates, "Points are always 3D.", count == 3);
+}
+#[test]
+fn small_heap_usage() {
+    let ids: Vec<u32> = Vec::with_capacity(16);
+    panic_irrelevant!(ids, "Only a handful of ids are ever buffered.", heap_bytes <= 1024);
+    let names = String::from("short");
+    panic_irrelevant!(names, "Only a handful of ids are ever buffered.", heap_bytes <= 1024);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(
+    expected = "Assumption violated: Only a handful of ids are ever buffered. (about 8000 bytes are allocated, more than 1024)"
+)]
+fn large_heap_usage() {
+    let ids: Vec<u64> = Vec::with_capacity(1000);
+    panic_irrelevant!(ids, "Only a handful of ids are ever buffered.", heap_bytes <= 1024);
+}
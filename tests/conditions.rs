@@ -0,0 +1,365 @@
+use irrelevant::*;
+use std::cell::Cell;
+
+#[test]
+fn lazy_expensive_skipped() {
+    let calls = Cell::new(0);
+    let expensive = || {
+        calls.set(calls.get() + 1);
+        false
+    };
+    let words = ["apple"];
+    panic_irrelevant!(words, "The words are sorted.", words.len() < 2 => expensive());
+    assert_eq!(calls.get(), 0);
+}
+#[cfg(feature = "checks")]
+#[test]
+fn lazy_expensive_runs() {
+    let calls = Cell::new(0);
+    let expensive = |words: &[&str]| {
+        calls.set(calls.get() + 1);
+        words.windows(2).all(|pair| pair[0] <= pair[1])
+    };
+    let words = ["apple", "banana", "cherry"];
+    panic_irrelevant!(words, "The words are sorted.", words.len() < 2 => expensive(&words));
+    assert_eq!(calls.get(), 1);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: The words are sorted.")]
+fn lazy_expensive_fails() {
+    let words = ["cherry", "apple"];
+    panic_irrelevant!(words, "The words are sorted.", words.len() < 2 => words.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+#[cfg(feature = "checks")]
+const SMALL: u64 = 1 << 20;
+#[cfg(feature = "checks")]
+#[test]
+fn lazy_comparison_guard() {
+    let calls = Cell::new(0);
+    let is_prime = |n: u64| {
+        calls.set(calls.get() + 1);
+        n > 1 && (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0)
+    };
+    let seed = 17;
+    panic_irrelevant!(seed, "Seeds are prime, or small enough not to matter.", seed < SMALL => is_prime(seed));
+    assert_eq!(calls.get(), 0);
+    let seed = SMALL + 7;
+    panic_irrelevant!(seed, "Seeds are prime, or small enough not to matter.", seed < SMALL => is_prime(seed));
+    assert_eq!(calls.get(), 1);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Seeds are prime, or small enough not to matter.")]
+fn lazy_comparison_guard_fails() {
+    let seed = SMALL;
+    panic_irrelevant!(seed, "Seeds are prime, or small enough not to matter.", seed < SMALL => seed % 2 == 1);
+}
+#[test]
+fn bounds_hold() {
+    let retries = 2;
+    panic_irrelevant!(retries, "Retries are capped.", < 3);
+    let retries = 3;
+    panic_irrelevant!(retries, "Retries are capped.", <= 3);
+    let port = 8080;
+    panic_irrelevant!(port, "Only unprivileged ports are used.", > 1023);
+    let port = 1024;
+    panic_irrelevant!(port, "Only unprivileged ports are used.", >= 1024);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Only unprivileged ports are used. (80 is not larger than 1023)")]
+fn bounds_violated() {
+    let port = 80;
+    panic_irrelevant!(port, "Only unprivileged ports are used.", > 1023);
+}
+#[test]
+fn bounds_in_parentheses() {
+    let retries = 2;
+    let max = 4;
+    panic_irrelevant!(retries, "Retries are capped.", < (max - 1));
+    let retries = 2;
+    let max = Box::new(3);
+    panic_irrelevant!(retries, "Retries are capped.", < *max);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Retries are capped. (3 is not smaller than 3)")]
+fn bounds_in_parentheses_violated() {
+    let retries = 3;
+    let max = 4;
+    panic_irrelevant!(retries, "Retries are capped.", < (max - 1) * 1);
+}
+trait Limited {
+    const LIMIT: u32;
+}
+impl Limited for u32 {
+    const LIMIT: u32 = 3;
+}
+#[test]
+fn qualified_paths_are_not_bounds() {
+    let retries = 2;
+    panic_irrelevant!(retries, "Retries are capped.", <u32 as Limited>::LIMIT > retries);
+    let bytes = [1, 2];
+    panic_irrelevant!(bytes, "Only short buffers are used.", <[u8]>::len(&bytes) < 3);
+    let first = 1u8;
+    panic_irrelevant!(first, "Only the type matters.", <[u8; 2] as IntoIterator>::Item);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Retries are capped.")]
+fn qualified_path_condition_violated() {
+    let retries = 3;
+    panic_irrelevant!(retries, "Retries are capped.", <u32 as Limited>::LIMIT > retries);
+}
+#[derive(Debug, PartialEq)]
+#[cfg_attr(not(feature = "checks"), allow(dead_code))]
+enum Status {
+    Ready,
+    Draining,
+}
+#[test]
+fn equality_holds() {
+    let status = Status::Ready;
+    panic_irrelevant!(status, "Requests are only handled once the server is ready.", == Status::Ready);
+    let name = String::from("main");
+    panic_irrelevant!(name, "Only the main inventory is supported.", == "main");
+}
+#[cfg(all(feature = "checks", not(feature = "pretty")))]
+#[test]
+#[should_panic(
+    expected = "Assumption violated: Requests are only handled once the server is ready. (got Draining, expected Ready)"
+)]
+fn equality_violated() {
+    let status = Status::Draining;
+    panic_irrelevant!(status, "Requests are only handled once the server is ready.", == Status::Ready);
+}
+#[cfg(feature = "pretty")]
+#[test]
+#[should_panic(
+    expected = "Assumption violated: Requests are only handled once the server is ready. (the values differ:"
+)]
+fn equality_violated_diff() {
+    let status = Status::Draining;
+    panic_irrelevant!(status, "Requests are only handled once the server is ready.", == Status::Ready);
+}
+struct Inventory {
+    name: String,
+    alias: String,
+    items: Vec<u32>,
+    count: usize,
+}
+#[test]
+fn conditions_on_fields() {
+    let inventory = Inventory {
+        name: "main".into(),
+        alias: "main".into(),
+        items: vec![1, 2],
+        count: 2,
+    };
+    panic_irrelevant!(inventory, "The inventory is consistent.", inventory.items.len() == inventory.count);
+    let inventory = Inventory {
+        name: "main".into(),
+        alias: "main".into(),
+        items: vec![],
+        count: 0,
+    };
+    panic_irrelevant!(inventory, "Aliases are not supported.", inventory.name == inventory.alias);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: The inventory is consistent.")]
+fn conditions_on_inconsistent_fields() {
+    let inventory = Inventory {
+        name: "main".into(),
+        alias: "spare".into(),
+        items: vec![1, 2, 3],
+        count: 2,
+    };
+    panic_irrelevant!(inventory, "The inventory is consistent.", inventory.items.len() == inventory.count && inventory.name == inventory.alias);
+}
+#[repr(C)]
+struct Rgba {
+    red: u8,
+    green: u8,
+    blue: u8,
+    alpha: u8,
+}
+#[test]
+fn same_layout() {
+    let color = Rgba {
+        red: 1,
+        green: 2,
+        blue: 3,
+        alpha: 4,
+    };
+    assert_eq!(color.red + color.green + color.blue + color.alpha, 10);
+    panic_irrelevant!(color, "The color is only passed to the GPU as a packed integer.", same_layout_as [u8; 4]);
+}
+#[test]
+fn valid_ranges() {
+    let span = 2..5;
+    panic_irrelevant!(span, "Spans come from the parser, so they are ordered.", valid_range);
+    let span = 3..3;
+    panic_irrelevant!(span, "Spans come from the parser, so they are ordered.", valid_range);
+    let span = 1..=4;
+    panic_irrelevant!(span, "Spans come from the parser, so they are ordered.", valid_range);
+    let span = ..4;
+    panic_irrelevant!(span, "Spans come from the parser, so they are ordered.", valid_range);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Spans come from the parser, so they are ordered. (the range starts at 5, after its end at 2)")]
+fn inverted_range() {
+    #[allow(clippy::reversed_empty_ranges)]
+    let span = 5..2;
+    panic_irrelevant!(span, "Spans come from the parser, so they are ordered.", valid_range);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Expected no sauces, but got 2.")]
+fn formatted_reason() {
+    let sauces = vec!["ketchup", "mustard"];
+    panic_irrelevant!(sauces, "Expected no sauces, but got {}.", sauces.len(); is_empty);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Expected at most 3 shots, but got 5 for a latte.")]
+fn formatted_reason_with_condition() {
+    let (shots, drink) = (5, "latte");
+    panic_irrelevant!(shots, "Expected at most {} shots, but got {} for a {drink}.", 3, shots; shots <= 3);
+}
+#[test]
+#[cfg(all(feature = "checks", feature = "std"))]
+fn formatted_reason_is_lazy() {
+    let formatted = Cell::new(0);
+    let describe = |sauces: &[&str]| {
+        formatted.set(formatted.get() + 1);
+        sauces.len()
+    };
+    let sauces: Vec<&str> = Vec::new();
+    irrelevant!(sauces, "Expected no sauces, but got {}.", describe(&sauces); sauces.is_empty());
+    assert_eq!(formatted.get(), 0);
+    let sauces = vec!["ketchup"];
+    irrelevant!(sauces, "Expected no sauces, but got {}.", describe(&sauces); sauces.is_empty());
+    assert_eq!(formatted.get(), 1);
+    // The formatted reason is recorded.
+    assert_eq!(last_violation().unwrap().reason, "Expected no sauces, but got 1.");
+}
+#[test]
+fn static_closure() {
+    let name = String::from("worker");
+    let job = move || name.len();
+    panic_irrelevant!(job, "The job is spawned onto another thread by the caller.", static_closure);
+    let callback = |x: u32| x + 1;
+    panic_irrelevant!(callback, "Callbacks are stored for the lifetime of the program.", static_closure);
+}
+#[test]
+fn ok_expected() {
+    let written: Result<usize, &str> = Ok(4);
+    panic_irrelevant!(written, "The buffer always fits in the pipe.", ok == 4);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: The buffer always fits in the pipe. (got Ok(2), expected Ok(4))")]
+fn ok_other() {
+    let written: Result<usize, &str> = Ok(2);
+    panic_irrelevant!(written, "The buffer always fits in the pipe.", ok == 4);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: The buffer always fits in the pipe. (got Err(\"broken pipe\"), expected Ok(4))")]
+fn ok_err() {
+    let written: Result<usize, &str> = Err("broken pipe");
+    panic_irrelevant!(written, "The buffer always fits in the pipe.", ok == 4);
+}
+#[test]
+fn static_size() {
+    struct Unprivileged;
+    let permissions = Unprivileged;
+    irrelevant_static!(permissions, "Unprivileged permissions carry no data.", type = Unprivileged, size = 0);
+    let _: ExplicitlyIgnoredValue = permissions;
+    let color = Rgba {
+        red: 1,
+        green: 2,
+        blue: 3,
+        alpha: 4,
+    };
+    assert_eq!(color.red + color.green + color.blue + color.alpha, 10);
+    irrelevant_static!(color, "Only the packed color is used.", type = Rgba, size = 4,);
+}
+#[test]
+fn ranges_hold() {
+    let len = 4;
+    let index = 3;
+    panic_irrelevant!(index, "Indices are always in bounds.", in 0..len);
+    let index = 4;
+    panic_irrelevant!(index, "Indices are at most the length.", in 0..=len);
+    let index = 0;
+    panic_irrelevant!(index, "Indices are at most the length.", in ..=len);
+    let index = usize::MAX;
+    panic_irrelevant!(index, "Any index is fine.", in ..);
+    let celsius = -3.5;
+    panic_irrelevant!(celsius, "The thermostat is indoors.", in -40.0..60.0);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Indices are always in bounds. (4 is not in 0..4)")]
+fn range_violated() {
+    let len = 4;
+    let index = 4;
+    panic_irrelevant!(index, "Indices are always in bounds.", in 0..len);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Indices are at most the length. (5 is not in ..=4)")]
+fn inclusive_range_violated() {
+    let index = 5;
+    panic_irrelevant!(index, "Indices are at most the length.", in ..=4);
+}
+#[test]
+fn condition_lists_hold() {
+    let buffer: Vec<u8> = Vec::new();
+    panic_irrelevant!(buffer, "Buffers are only allocated when written to.", [buffer.is_empty(), buffer.capacity() == 0]);
+    let port = 8080;
+    panic_irrelevant!(port, "Only unprivileged ports are used.", [port >= 1024,]);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(
+    expected = "Assumption violated: Buffers are only allocated when written to. (assumption 1 (`buffer.capacity() == 0`) doesn't hold)"
+)]
+fn condition_list_violated() {
+    let buffer: Vec<u8> = Vec::with_capacity(16);
+    panic_irrelevant!(buffer, "Buffers are only allocated when written to.", [buffer.is_empty(), buffer.capacity() == 0]);
+}
+#[cfg(all(feature = "checks", feature = "std"))]
+#[test]
+fn condition_list_reports_every_violation() {
+    let violations = capture_violations(|| {
+        let (start, end) = (3, 3);
+        irrelevant!(start, "Ranges are never empty or reversed.", [start < end, start != end, end > 0]);
+    });
+    let details: Vec<_> = violations.iter().map(|violation| violation.details.as_deref().unwrap()).collect();
+    assert_eq!(details, ["assumption 0 (`start < end`) doesn't hold", "assumption 1 (`start != end`) doesn't hold"]);
+}
+#[test]
+fn none_when_holds() {
+    use std::num::NonZeroU64;
+    let saved = false;
+    let id: Option<NonZeroU64> = None;
+    panic_irrelevant!(id, "Ids are only assigned once a record is saved.", none_when(!saved));
+    let saved = true;
+    let id = NonZeroU64::new(7);
+    panic_irrelevant!(id, "Ids are only assigned once a record is saved.", none_when(!saved));
+    let id: Option<NonZeroU64> = None;
+    panic_irrelevant!(id, "Ids are only assigned once a record is saved.", none_when(!saved));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Ids are only assigned once a record is saved. (got Some(7), expected None)")]
+fn none_when_violated() {
+    let saved = false;
+    let id = std::num::NonZeroU64::new(7);
+    panic_irrelevant!(id, "Ids are only assigned once a record is saved.", none_when(!saved));
+}
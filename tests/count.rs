@@ -0,0 +1,32 @@
+#![cfg(feature = "checks")]
+use irrelevant::*;
+
+/// Runs as a single test, since the count is shared by all the threads.
+#[test]
+fn violations_are_counted() {
+    reset_violation_count();
+    let sauces: [&str; 0] = [];
+    irrelevant!(sauces, "No sauces should come with a drink!", is_empty);
+    assert_eq!(violation_count(), 0);
+
+    let sauces = ["ketchup"];
+    irrelevant!(sauces, "No sauces should come with a drink!", is_empty);
+    let ice = 3;
+    irrelevant!(ice, "Hot drinks come without ice.", <= 0);
+    assert_eq!(violation_count(), 2);
+    let result = std::panic::catch_unwind(|| {
+        let sauces = ["ketchup"];
+        panic_irrelevant!(sauces, "No sauces should come with a drink!", is_empty);
+    });
+    assert!(result.is_err());
+    std::thread::spawn(|| {
+        let sauces = ["ketchup"];
+        irrelevant!(sauces, "No sauces should come with a drink!", is_empty);
+    })
+    .join()
+    .unwrap();
+    assert_eq!(violation_count(), 4);
+
+    reset_violation_count();
+    assert_eq!(violation_count(), 0);
+}
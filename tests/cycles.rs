@@ -0,0 +1,64 @@
+use irrelevant::checks::Traverse;
+use irrelevant::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+struct Node {
+    name: &'static str,
+    children: RefCell<Vec<NodeRef>>,
+}
+#[derive(Clone)]
+struct NodeRef(Rc<Node>);
+impl NodeRef {
+    fn new(name: &'static str) -> Self {
+        Self(Rc::new(Node {
+            name,
+            children: RefCell::new(Vec::new()),
+        }))
+    }
+    fn link(&self, child: &NodeRef) {
+        self.0.children.borrow_mut().push(child.clone());
+    }
+}
+impl Traverse for NodeRef {
+    type Id = &'static str;
+    fn id(&self) -> Self::Id {
+        self.0.name
+    }
+    fn children(&self) -> Vec<Self> {
+        self.0.children.borrow().clone()
+    }
+}
+#[test]
+fn acyclic() {
+    // A diamond: `leaf` is reachable twice, but not from itself.
+    let root = NodeRef::new("root");
+    let left = NodeRef::new("left");
+    let right = NodeRef::new("right");
+    let leaf = NodeRef::new("leaf");
+    root.link(&left);
+    root.link(&right);
+    left.link(&leaf);
+    right.link(&leaf);
+    panic_irrelevant!(root, "Parents are only referenced weakly, so the tree is freed.", no_cycle);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(
+    expected = "Assumption violated: Parents are only referenced weakly, so the tree is freed. (\"child\" is part of a cycle)"
+)]
+fn cyclic() {
+    let root = NodeRef::new("root");
+    let child = NodeRef::new("child");
+    let grandchild = NodeRef::new("grandchild");
+    root.link(&child);
+    child.link(&grandchild);
+    grandchild.link(&child);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let root = root.clone();
+        panic_irrelevant!(root, "Parents are only referenced weakly, so the tree is freed.", no_cycle);
+    }));
+    // Breaks the cycle, so the nodes don't leak.
+    child.0.children.borrow_mut().clear();
+    std::panic::resume_unwind(result.unwrap_err());
+}
@@ -0,0 +1,49 @@
+use irrelevant::*;
+
+#[derive(Debug)]
+struct Drink {
+    ice: u32,
+}
+/// A type without a `Debug` implementation.
+#[cfg(feature = "checks")]
+struct Straw {
+    length: u32,
+}
+#[test]
+fn dbg_holds() {
+    let drink = Drink { ice: 0 };
+    panic_irrelevant!(drink, "Hot drinks come without ice.", dbg drink.ice == 0);
+    let sauces: Vec<&str> = Vec::new();
+    panic_irrelevant!(sauces, "No sauces should come with a drink!", dbg is_empty);
+    let pending: Vec<&str> = Vec::new();
+    panic_irrelevant!(pending, "Orders are all served before closing.", dbg drained);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Hot drinks come without ice. (value = Drink { ice: 2 })")]
+fn dbg_violated() {
+    let drink = Drink { ice: 2 };
+    panic_irrelevant!(drink, "Hot drinks come without ice.", dbg drink.ice == 0);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: No sauces should come with a drink! (value = [\"ketchup\"])")]
+fn dbg_method_violated() {
+    let sauces = vec!["ketchup"];
+    panic_irrelevant!(sauces, "No sauces should come with a drink!", dbg is_empty);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Orders are all served before closing. (1 entries remain, value = [\"tea\"])")]
+fn dbg_assumption_violated() {
+    let pending = vec!["tea"];
+    panic_irrelevant!(pending, "Orders are all served before closing.", dbg drained);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Straws are at most 20 cm long.")]
+fn without_debug() {
+    // Without `dbg`, the value doesn't have to implement `Debug`, and is not reported.
+    let straw = Straw { length: 25 };
+    panic_irrelevant!(straw, "Straws are at most 20 cm long.", straw.length <= 20);
+}
@@ -0,0 +1,38 @@
+#![cfg(all(feature = "checks", debug_assertions, not(feature = "build-script")))]
+use irrelevant::*;
+use std::process::Command;
+
+const MOCK_ENV: &str = "IRRELEVANT_MOCK_DEBUG";
+/// Checks assumptions with `debug_irrelevant`, when run by [`only_violations_are_reported`].
+#[test]
+fn mock_debug_checks() {
+    if std::env::var_os(MOCK_ENV).is_none() {
+        return;
+    }
+    let sauces: [&str; 0] = [];
+    debug_irrelevant!(sauces, "Holds: no sauces should come with a drink!", is_empty);
+    let ice = 0;
+    debug_irrelevant!(ice, "Holds: hot drinks come without ice.", ice == 0);
+    let sauces = ["ketchup"];
+    debug_irrelevant!(sauces, "Violated: no sauces should come with a drink!", is_empty);
+    let ice = 3;
+    debug_irrelevant!(ice, "Violated: hot drinks come without ice.", ice == 0);
+}
+#[test]
+fn only_violations_are_reported() {
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", "mock_debug_checks", "--nocapture", "--test-threads=1"])
+        .env(MOCK_ENV, "1")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let violations: Vec<_> = stderr.lines().filter(|line| line.contains("Assumption violated")).collect();
+    assert_eq!(
+        violations,
+        [
+            "[tests/debug.rs:17:5] Assumption violated: Violated: no sauces should come with a drink!",
+            "[tests/debug.rs:19:5] Assumption violated: Violated: hot drinks come without ice.",
+        ]
+    );
+}
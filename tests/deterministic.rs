@@ -0,0 +1,46 @@
+use irrelevant::*;
+
+/// Ignores the hash of a cache key, which is only used for logging.
+fn log_key(hash: u64) {
+    panic_irrelevant!(hash, "Keys are hashed deterministically, so the logs only show the first hash.", deterministic);
+}
+#[test]
+fn deterministic_values() {
+    for _ in 0..3 {
+        log_key(0xfeed);
+    }
+}
+/// Ignores the layout of a page, which is only computed to warm up the cache.
+#[cfg(feature = "checks")]
+fn warm_up(layout: Vec<&'static str>) {
+    panic_irrelevant!(layout, "Layouts are pure, so the first one was cached.", deterministic);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(
+    expected = "Assumption violated: Layouts are pure, so the first one was cached. ([\"header\", \"footer\"] differs from the first value, [\"header\", \"body\"])"
+)]
+fn nondeterministic_values() {
+    warm_up(vec!["header", "body"]);
+    warm_up(vec!["header", "body"]);
+    warm_up(vec!["header", "footer"]);
+}
+/// Ignores a value of any type, at a single place.
+fn ignore<T: Clone + PartialEq + Send + std::fmt::Debug + 'static>(value: T) {
+    panic_irrelevant!(value, "Only the first value of each type matters.", deterministic);
+}
+#[test]
+fn different_types() {
+    ignore(1u32);
+    ignore(2u64);
+    ignore(1u32);
+    ignore(2u64);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "(2 differs from the first value, 1)")]
+fn different_values_of_a_type() {
+    ignore(1u8);
+    ignore(2u16);
+    ignore(2u8);
+}
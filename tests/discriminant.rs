@@ -0,0 +1,33 @@
+use irrelevant::checks::Discriminant;
+use irrelevant::*;
+
+checked_enum! {
+    #[repr(i32)]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Status {
+        Ok = 0,
+        /// Variants may have attributes too.
+        Retry = 5,
+        Failed,
+    }
+}
+#[test]
+fn discriminants() {
+    assert!(Status::is_discriminant(0));
+    assert!(Status::is_discriminant(5));
+    assert!(Status::is_discriminant(6));
+    assert!(!Status::is_discriminant(1));
+    assert_eq!(Status::Failed as i32, 6);
+}
+#[test]
+fn valid_discriminant() {
+    let status = 5;
+    panic_irrelevant!(status, "The library only returns known statuses.", valid_variant: Status);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: The library only returns known statuses. (-1 is not a discriminant of discriminant::Status)")]
+fn invalid_discriminant() {
+    let status = -1;
+    panic_irrelevant!(status, "The library only returns known statuses.", valid_variant: Status);
+}
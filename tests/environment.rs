@@ -0,0 +1,58 @@
+#![cfg(feature = "checks")]
+use irrelevant::*;
+
+/// Runs as a single test, since the environment is shared by all the threads.
+#[test]
+fn timezone() {
+    let timestamp = 1_700_000_000;
+    std::env::set_var("TZ", "UTC");
+    irrelevant!(timestamp, "Logs are always written in UTC.", utc);
+    assert_eq!(last_violation(), None);
+    std::env::set_var("TZ", ":Etc/UTC");
+    irrelevant!(timestamp, "Logs are always written in UTC.", utc);
+    assert_eq!(last_violation(), None);
+    std::env::set_var("TZ", "Europe/Warsaw");
+    irrelevant!(timestamp, "Reports use the local time of the office.", tz == "Europe/Warsaw");
+    assert_eq!(last_violation(), None);
+
+    irrelevant!(timestamp, "Logs are always written in UTC.", utc);
+    assert_eq!(last_violation().unwrap().reason, "Logs are always written in UTC.");
+    let result = std::panic::catch_unwind(|| {
+        panic_irrelevant!(timestamp, "Logs are always written in UTC.", utc);
+    });
+    let message = *result.unwrap_err().downcast::<String>().unwrap();
+    assert!(message.ends_with("(TZ is \"Europe/Warsaw\")"), "{message}");
+
+    std::env::remove_var("TZ");
+    let result = std::panic::catch_unwind(|| {
+        panic_irrelevant!(timestamp, "Reports use the local time of the office.", tz == "Europe/Warsaw");
+    });
+    let message = *result.unwrap_err().downcast::<String>().unwrap();
+    assert!(message.ends_with("(TZ is not set)"), "{message}");
+}
+/// Uses its own variables, so it doesn't interfere with [`timezone`].
+#[test]
+fn environment() {
+    let cache = ();
+    std::env::set_var("APP_ENV", "production");
+    panic_irrelevant!(cache, "Production always runs with a warm cache.", env_is("production"));
+    std::env::set_var("IRRELEVANT_DEPLOYMENT", "development");
+    let tracing = ();
+    panic_irrelevant!(tracing, "Tracing is only off in development.", env_is("development", var = "IRRELEVANT_DEPLOYMENT"));
+
+    std::env::set_var("APP_ENV", "staging");
+    let result = std::panic::catch_unwind(|| {
+        let cache = ();
+        panic_irrelevant!(cache, "Production always runs with a warm cache.", env_is("production"));
+    });
+    let message = *result.unwrap_err().downcast::<String>().unwrap();
+    assert!(message.ends_with("(APP_ENV is \"staging\")"), "{message}");
+
+    std::env::remove_var("IRRELEVANT_DEPLOYMENT");
+    let result = std::panic::catch_unwind(|| {
+        let tracing = ();
+        panic_irrelevant!(tracing, "Tracing is only off in development.", env_is("development", var = "IRRELEVANT_DEPLOYMENT"));
+    });
+    let message = *result.unwrap_err().downcast::<String>().unwrap();
+    assert!(message.ends_with("(IRRELEVANT_DEPLOYMENT is not set)"), "{message}");
+}
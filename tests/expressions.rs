@@ -0,0 +1,82 @@
+use irrelevant::*;
+
+struct Renderer {
+    cache: Vec<u32>,
+    depth: usize,
+}
+impl Renderer {
+    fn layers(&self) -> Vec<u32> {
+        vec![0; self.depth]
+    }
+    fn render(&self) -> usize {
+        irrelevant!(self.depth, "Layers are rendered flat.", <= 1);
+        irrelevant!(&self.cache, "Nothing is cached before the first frame.", is_empty);
+        irrelevant!(self.layers(), "Layers are rendered flat.", drained);
+        irrelevant!(self.cache.len(), "The cache is unused for now.");
+        irrelevant!(self.layers());
+        self.depth
+    }
+}
+#[test]
+fn fields_and_calls() {
+    let renderer = Renderer { cache: Vec::new(), depth: 0 };
+    assert_eq!(renderer.render(), 0);
+    // The fields are still usable after being ignored.
+    assert!(renderer.cache.is_empty());
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Layers are rendered flat. (2 entries remain)")]
+fn call_result_violated() {
+    let renderer = Renderer { cache: Vec::new(), depth: 2 };
+    panic_irrelevant!(renderer.layers(), "Layers are rendered flat.", drained);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Nothing is cached before the first frame.")]
+fn field_violated() {
+    let renderer = Renderer { cache: vec![7], depth: 0 };
+    panic_irrelevant!(&renderer.cache, "Nothing is cached before the first frame.", is_empty);
+}
+#[test]
+fn expressions_are_dropped() {
+    let counter = std::rc::Rc::new(());
+    irrelevant!(std::rc::Rc::clone(&counter), "The clone is only made for its side effects.", unique);
+    assert_eq!(std::rc::Rc::strong_count(&counter), 1);
+}
+struct Metrics {
+    samples: Vec<u32>,
+}
+impl Metrics {
+    fn name(&self) -> &'static str {
+        panic_irrelevant!(self, "All the metrics share a name until sampling is implemented.", self.samples.is_empty());
+        "metrics"
+    }
+    fn unit(&self) -> &'static str {
+        panic_irrelevant!(self, "All the metrics are counted.");
+        panic_irrelevant!(self, "All the metrics are counted.", &Metrics);
+        panic_irrelevant!(self, "All the metrics are counted.", samples_counted);
+        "count"
+    }
+    fn samples_counted(&self) -> bool {
+        self.samples.iter().all(|&sample| sample <= 1)
+    }
+    fn into_samples(self) -> Vec<u32> {
+        panic_irrelevant!(self, "Samples are moved out as they are.", samples_counted);
+        self.samples
+    }
+}
+#[test]
+fn receiver_holds() {
+    let metrics = Metrics { samples: Vec::new() };
+    assert_eq!(metrics.name(), "metrics");
+    assert_eq!(metrics.unit(), "count");
+    assert!(metrics.into_samples().is_empty());
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: All the metrics share a name until sampling is implemented.")]
+fn receiver_violated() {
+    let metrics = Metrics { samples: vec![3] };
+    metrics.name();
+}
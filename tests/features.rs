@@ -0,0 +1,32 @@
+use irrelevant::*;
+
+#[test]
+fn consistent_features() {
+    let allocator = "system";
+    panic_irrelevant!(allocator, "The allocator is only configurable with std.", cfg_consistent(feature = "std"));
+    let buffer = ();
+    panic_irrelevant!(buffer, "Always relevant on other targets.", cfg_consistent(any(unix, windows, not(unix))));
+}
+#[cfg(all(feature = "checks", not(feature = "snapshot")))]
+#[test]
+#[should_panic(
+    expected = "Assumption violated: Snapshots are only stored with the snapshot feature. (the cfg flags do not satisfy `all(feature = \"std\", feature = \"snapshot\")`)"
+)]
+fn inconsistent_features() {
+    let path = "snapshot.txt";
+    panic_irrelevant!(
+        path,
+        "Snapshots are only stored with the snapshot feature.",
+        cfg_consistent(all(feature = "std", feature = "snapshot"))
+    );
+}
+#[cfg(feature = "snapshot")]
+#[test]
+fn snapshot_features() {
+    let path = "snapshot.txt";
+    panic_irrelevant!(
+        path,
+        "Snapshots are only stored with the snapshot feature.",
+        cfg_consistent(all(feature = "std", feature = "snapshot"))
+    );
+}
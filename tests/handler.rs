@@ -0,0 +1,36 @@
+#![cfg(feature = "checks")]
+use irrelevant::*;
+use std::sync::Mutex;
+
+static HANDLED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+static RECORDS: Mutex<Vec<ViolationRecord>> = Mutex::new(Vec::new());
+fn collect(violation: &ViolationInfo) {
+    HANDLED.lock().unwrap().push(violation.to_string());
+    RECORDS.lock().unwrap().push(violation.into());
+}
+// The handler is global, so all the scenarios share a single test.
+#[test]
+fn violations_go_to_the_handler() {
+    set_violation_handler(collect);
+    let sauces: [&str; 0] = [];
+    irrelevant!(sauces, "No sauces should come with a drink!", is_empty);
+    assert!(HANDLED.lock().unwrap().is_empty());
+
+    let sauces = ["ketchup"];
+    irrelevant!(sauces, "No sauces should come with a drink!", is_empty);
+    let ice = 3;
+    irrelevant!(ice, "Hot drinks come with at most {} cubes.", 2; ice <= 2);
+    let sauces = vec!["ketchup", "mustard"];
+    irrelevant!(sauces, "No sauces should come with a drink!", drained);
+    assert_eq!(
+        *HANDLED.lock().unwrap(),
+        [
+            "[tests/handler.rs:20:5] Assumption violated: No sauces should come with a drink!",
+            "[tests/handler.rs:22:5] Assumption violated: Hot drinks come with at most 2 cubes.",
+            "[tests/handler.rs:24:5] Assumption violated: No sauces should come with a drink! (2 entries remain)",
+        ]
+    );
+    // Violations are still recorded, and their records own the formatted reasons.
+    assert_eq!(last_violation().unwrap().location.line, 24);
+    assert_eq!(RECORDS.lock().unwrap()[1].reason, "Hot drinks come with at most 2 cubes.");
+}
@@ -0,0 +1,24 @@
+use irrelevant::*;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+struct Order {
+    dish: &'static str,
+    sauces: ExplicitlyIgnoredValue,
+}
+#[test]
+fn ignored_values_can_be_stored() {
+    let sauces = ["ketchup"];
+    irrelevant!(sauces, "Sauces are served separately.");
+    let order = Order { dish: "fries", sauces };
+    assert_eq!(order.clone(), order);
+    assert_eq!(Order::default().sauces, ExplicitlyIgnoredValue);
+    assert_eq!(HashSet::from([order.sauces, sauces]).len(), 1);
+    assert_eq!(format!("{order:?}"), "Order { dish: \"fries\", sauces: ExplicitlyIgnoredValue }");
+}
+#[test]
+fn ignored_values_display() {
+    let ice = 0;
+    irrelevant!(ice, "Hot drinks come without ice.", == 0);
+    assert_eq!(ice.to_string(), "<explicitly ignored>");
+}
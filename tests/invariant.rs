@@ -0,0 +1,28 @@
+use irrelevant::checks::Invariant;
+use irrelevant::*;
+
+struct Span {
+    start: usize,
+    end: usize,
+}
+impl Invariant for Span {
+    fn holds(&self) -> bool {
+        self.start <= self.end
+    }
+}
+#[test]
+fn invariant_holds() {
+    let span = Span { start: 1, end: 4 };
+    panic_irrelevant!(span, "Highlighting is not supported yet.", invariant);
+    let span = &Span { start: 4, end: 4 };
+    panic_irrelevant!(span, "Empty spans are never highlighted.", invariant);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(
+    expected = "Assumption violated: Highlighting is not supported yet. (the invariant of invariant::Span does not hold)"
+)]
+fn invariant_violated() {
+    let span = Span { start: 4, end: 1 };
+    panic_irrelevant!(span, "Highlighting is not supported yet.", invariant);
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "checks")]
+use irrelevant::*;
+use std::fs;
+use std::num::NonZeroU32;
+use std::path::PathBuf;
+
+fn log_path(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("irrelevant-log-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    let _ = fs::remove_file(&path);
+    let mut rotated = path.clone().into_os_string();
+    rotated.push(".1");
+    let _ = fs::remove_file(rotated);
+    path
+}
+fn violate() {
+    let sauces = ["ketchup"];
+    irrelevant!(sauces, "No sauces should come with a drink!", is_empty);
+}
+// The violation file is global, so all the scenarios share a single test.
+#[test]
+fn violation_file() {
+    // Violations are appended, one per line.
+    let path = log_path("violations.log");
+    set_violation_file(&path, 1024).unwrap();
+    violate();
+    violate();
+    let logged = fs::read_to_string(&path).unwrap();
+    let lines: Vec<_> = logged.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("[tests/log_file.rs:19:5] Assumption violated: No sauces should come with a drink!"));
+
+    // A full file is rotated.
+    let line_len = lines[0].len() as u64 + 1;
+    let path = log_path("rotated.log");
+    set_violation_file(&path, line_len * 3).unwrap();
+    for _ in 0..4 {
+        violate();
+    }
+    let mut rotated = path.clone().into_os_string();
+    rotated.push(".1");
+    assert_eq!(fs::read_to_string(rotated).unwrap().lines().count(), 3);
+    assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 1);
+
+    // Only sampled violations are appended.
+    let path = log_path("sampled.log");
+    set_violation_file(&path, 1024).unwrap();
+    set_violation_sampling(NonZeroU32::new(3).unwrap());
+    for _ in 0..9 {
+        violate();
+    }
+    assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 3);
+    set_violation_sampling(NonZeroU32::new(1).unwrap());
+
+    // Nothing is appended after clearing the file.
+    clear_violation_file();
+    violate();
+    assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 3);
+}
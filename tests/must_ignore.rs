@@ -0,0 +1,39 @@
+#![deny(unused_must_use)]
+use irrelevant::*;
+
+/// Closes a connection, returning the number of requests which were still pending.
+fn close(pending: &mut Vec<u32>) -> MustIgnore<usize> {
+    let count = pending.len();
+    pending.clear();
+    MustIgnore::new(count)
+}
+#[test]
+fn must_ignore_holds() {
+    let mut pending = Vec::new();
+    let dropped = close(&mut pending);
+    irrelevant!(must dropped, "Connections are only closed when idle.", dropped == 0);
+    irrelevant!(must close(&mut pending), "Connections are only closed when idle.", == 0);
+    irrelevant!(must close(&mut pending), "Connections are only closed when idle.");
+    let dropped = close(&mut pending);
+    irrelevant!(must dropped);
+    assert_eq!(dropped, ExplicitlyIgnoredValue);
+}
+#[cfg(all(feature = "checks", feature = "std"))]
+#[test]
+fn must_ignore_violated() {
+    let violations = capture_violations(|| {
+        let mut pending = vec![1, 2];
+        let dropped = close(&mut pending);
+        irrelevant!(must dropped, "Connections are only closed when idle.", dropped == 0);
+        let mut pending = vec![3];
+        irrelevant!(must close(&mut pending), "Connections are only closed when idle.", < 1);
+    });
+    let messages: Vec<String> = violations.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        messages,
+        [
+            "[tests/must_ignore.rs:27:9] Assumption violated: Connections are only closed when idle.",
+            "[tests/must_ignore.rs:29:9] Assumption violated: Connections are only closed when idle. (1 is not smaller than 1)",
+        ]
+    );
+}
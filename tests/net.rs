@@ -0,0 +1,17 @@
+use irrelevant::*;
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr};
+
+#[test]
+fn loopback_address() {
+    let addr = SocketAddr::from((Ipv4Addr::LOCALHOST, 8080));
+    panic_irrelevant!(addr, "The debug server only listens locally.", loopback);
+    let addr = SocketAddr::from((Ipv6Addr::LOCALHOST, 8080));
+    panic_irrelevant!(addr, "The debug server only listens locally.", loopback);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: The debug server only listens locally. (192.168.0.7:8080 is not a loopback address)")]
+fn non_loopback_address() {
+    let addr: SocketAddr = "192.168.0.7:8080".parse().unwrap();
+    panic_irrelevant!(addr, "The debug server only listens locally.", loopback);
+}
@@ -0,0 +1,15 @@
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn builds_without_std() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let status = Command::new(env!("CARGO"))
+        .args(["build", "--offline", "--quiet", "--manifest-path"])
+        .arg(manifest_dir.join("tests/no_std_crate/Cargo.toml"))
+        .arg("--target-dir")
+        .arg(manifest_dir.join("target/no_std"))
+        .status()
+        .unwrap();
+    assert!(status.success(), "the no_std crate failed to build");
+}
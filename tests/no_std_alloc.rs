@@ -0,0 +1,15 @@
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn keeps_violations_without_std() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let status = Command::new(env!("CARGO"))
+        .args(["test", "--offline", "--quiet", "--manifest-path"])
+        .arg(manifest_dir.join("tests/no_std_alloc_crate/Cargo.toml"))
+        .arg("--target-dir")
+        .arg(manifest_dir.join("target/no_std_alloc"))
+        .status()
+        .unwrap();
+    assert!(status.success(), "the tests of the no_std crate with an allocator failed");
+}
@@ -0,0 +1,63 @@
+//! A `no_std` crate with an allocator, keeping records of violations. Tested by `tests/no_std_alloc.rs`.
+#![no_std]
+extern crate alloc;
+#[cfg(test)]
+extern crate std;
+use alloc::vec::Vec;
+use core::cell::UnsafeCell;
+use core::sync::atomic::{AtomicBool, Ordering};
+use irrelevant::*;
+
+/// The violations reported to [`to_sink`], behind a spin lock, since there is no `Mutex` without `std`.
+struct Sink {
+    locked: AtomicBool,
+    violations: UnsafeCell<Vec<ViolationRecord>>,
+}
+// SAFETY: The violations are only accessed while holding the lock.
+unsafe impl Sync for Sink {}
+impl Sink {
+    fn with<R>(&self, f: impl FnOnce(&mut Vec<ViolationRecord>) -> R) -> R {
+        while self.locked.swap(true, Ordering::Acquire) {
+            core::hint::spin_loop();
+        }
+        // SAFETY: The lock is held, so nothing else accesses the violations.
+        let result = f(unsafe { &mut *self.violations.get() });
+        self.locked.store(false, Ordering::Release);
+        result
+    }
+}
+static SINK: Sink = Sink { locked: AtomicBool::new(false), violations: UnsafeCell::new(Vec::new()) };
+fn to_sink(violation: &ViolationInfo) {
+    SINK.with(|violations| violations.push(violation.into()));
+}
+/// Returns the violations reported so far, and forgets them.
+pub fn take_violations() -> Vec<ViolationRecord> {
+    SINK.with(core::mem::take)
+}
+pub fn resize(width: usize, height: usize) -> usize {
+    set_violation_handler(to_sink);
+    let area = width;
+    irrelevant!(area, "Grids are small, got one {} cells wide.", width; no_overflow_mul(height));
+    let scale = height;
+    irrelevant!(scale, "Grids are never scaled past {} cells.", 4 * width; scale <= 4);
+    width
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_own_formatted_reasons() {
+        resize(usize::MAX, 2);
+        let violations = take_violations();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].reason, "Grids are small, got one 18446744073709551615 cells wide.");
+        assert_eq!(violations[0].location.line, 40);
+        resize(3, 8);
+        let violations = take_violations();
+        assert_eq!(violations[0].reason, "Grids are never scaled past 12 cells.");
+        assert_eq!(violations[0].location.file, "src/lib.rs");
+        resize(3, 2);
+        assert!(take_violations().is_empty());
+    }
+}
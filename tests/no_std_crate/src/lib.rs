@@ -0,0 +1,19 @@
+//! A `no_std` crate using the macros, to check they don't need `std`. Built by `tests/no_std.rs`.
+#![no_std]
+use irrelevant::*;
+
+fn to_nowhere(violation: &ViolationInfo) {
+    let _ = violation;
+}
+pub fn handle(flags: u8, permissions: &(), width: usize, height: usize) -> u32 {
+    set_violation_handler(to_nowhere);
+    irrelevant!(permissions, "Reading flags does not require any privileges.", &());
+    debug_irrelevant!(flags, "Exactly one mode is selected.", popcount == 1);
+    let (x, y) = (0, 0);
+    panic_irrelevant!([x, y], "The origin is always on the grid.", in_grid(width, height));
+    let area = width;
+    irrelevant!(area, "Grids are small, got one {} cells wide.", width; no_overflow_mul(height));
+    let unused = ();
+    irrelevant!(unused);
+    1
+}
@@ -0,0 +1,230 @@
+use irrelevant::checks::Ulps;
+use irrelevant::*;
+
+#[test]
+fn ulps_distance() {
+    assert_eq!(1.0f64.ulps_between(1.0 + f64::EPSILON), 1);
+    assert_eq!(0.0f64.ulps_between(-0.0), 0);
+    assert_eq!(f32::from_bits(1).ulps_between(-f32::from_bits(1)), 2);
+    assert_eq!(f64::NAN.ulps_between(f64::NAN), u64::MAX);
+}
+#[test]
+fn within_ulps_equal() {
+    let sum = 0.5f64 + 0.25;
+    panic_irrelevant!(sum, "The sum is exact.", within_ulps(0.75, 0));
+}
+#[test]
+fn within_ulps_one_apart() {
+    let sum = 0.1f64 + 0.2;
+    panic_irrelevant!(sum, "The rounding error is tiny.", within_ulps(0.3, 1));
+    let sum = 1.0f32 + f32::EPSILON;
+    panic_irrelevant!(sum, "The rounding error is tiny.", within_ulps(1.0, 2));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: The rounding error is tiny. (1 ULPs away, at most 0 allowed)")]
+fn within_ulps_one_apart_exact() {
+    let sum = 0.1f64 + 0.2;
+    panic_irrelevant!(sum, "The rounding error is tiny.", within_ulps(0.3, 0));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: The rounding error is tiny. (1000 ULPs away, at most 2 allowed)")]
+fn within_ulps_many_apart() {
+    let value = f64::from_bits(1.0f64.to_bits() + 1000);
+    panic_irrelevant!(value, "The rounding error is tiny.", within_ulps(1.0, 2));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "(a NaN is infinitely many ULPs away, at most 2 allowed)")]
+fn within_ulps_nan() {
+    let value = f64::NAN;
+    panic_irrelevant!(value, "The rounding error is tiny.", within_ulps(1.0, 2));
+}
+#[test]
+fn plausible_readings() {
+    let celsius = 21.5;
+    panic_irrelevant!(celsius, "The thermostat is indoors.", plausible(-40.0, 60.0));
+    let celsius = 60.0;
+    panic_irrelevant!(celsius, "The thermostat is indoors.", plausible(-40.0, 60.0));
+    let humidity: u8 = 40;
+    panic_irrelevant!(humidity, "Humidity is a percentage.", plausible(0, 100));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: The thermostat is indoors. (the reading 450.0 is outside of -40.0..=60.0)")]
+fn out_of_range_reading() {
+    let celsius = 450.0;
+    panic_irrelevant!(celsius, "The thermostat is indoors.", plausible(-40.0, 60.0));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "(the reading NaN is outside of -40.0..=60.0)")]
+fn nan_reading() {
+    let celsius = f32::NAN;
+    panic_irrelevant!(celsius, "The thermostat is indoors.", plausible(-40.0, 60.0));
+}
+#[test]
+fn coordinates_in_grid() {
+    let (width, height) = (8, 4);
+    let (x, y) = (7, 3);
+    panic_irrelevant!([x, y], "The cursor is clamped to the board.", in_grid(width, height));
+    let (x, y) = (0_usize, 0_usize);
+    panic_irrelevant!([x, y], "The cursor is clamped to the board.", in_grid(1, 1));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: The cursor is clamped to the board. ((8, 2) is outside of a 8x4 grid)")]
+fn coordinates_outside_grid() {
+    let (width, height) = (8, 4);
+    let (x, y) = (8, 2);
+    panic_irrelevant!([x, y], "The cursor is clamped to the board.", in_grid(width, height));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "((1, 4) is outside of a 8x4 grid)")]
+fn row_outside_grid() {
+    let (x, y) = (1, 4);
+    panic_irrelevant!([x, y], "The cursor is clamped to the board.", in_grid(8, 4));
+}
+#[test]
+fn no_overflow() {
+    let total: u8 = 200;
+    panic_irrelevant!(total, "Totals are capped well below the limit.", no_overflow_add(55));
+    let stock: u32 = 10;
+    panic_irrelevant!(stock, "Orders are validated against the stock.", no_overflow_sub(10));
+    let area: i32 = -1000;
+    panic_irrelevant!(area, "Areas are small.", no_overflow_mul(1000));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Totals are capped well below the limit. (200 + 56 overflows)")]
+fn add_overflows() {
+    let total: u8 = 200;
+    panic_irrelevant!(total, "Totals are capped well below the limit.", no_overflow_add(56));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Orders are validated against the stock. (10 - 11 overflows)")]
+fn sub_overflows() {
+    let stock: u32 = 10;
+    panic_irrelevant!(stock, "Orders are validated against the stock.", no_overflow_sub(11));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Areas are small. (-65536 * 65536 overflows)")]
+fn mul_overflows() {
+    let area: i32 = -65536;
+    panic_irrelevant!(area, "Areas are small.", no_overflow_mul(65536));
+}
+#[test]
+fn close_near_zero() {
+    // Near zero, the absolute tolerance dominates.
+    let residual = 5e-9;
+    panic_irrelevant!(residual, "The solver converged.", close_to(0.0, rtol = 1e-5, atol = 1e-8));
+    let residual: f32 = -5e-9;
+    panic_irrelevant!(residual, "The solver converged.", close_to(0.0));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: The solver converged. (0.00001 is not close to 0 (rtol = 0.00001, atol = 0.00000001))")]
+fn not_close_near_zero() {
+    // Relative to zero, any difference is infinitely large.
+    let residual = 1e-5;
+    panic_irrelevant!(residual, "The solver converged.", close_to(0.0));
+}
+#[test]
+fn close_far_from_zero() {
+    // Far from zero, the relative tolerance dominates.
+    let distance = 1_000_000.5;
+    panic_irrelevant!(distance, "The distance was measured precisely.", close_to(1e6, rtol = 1e-6, atol = 0.0));
+    let infinite = f64::INFINITY;
+    panic_irrelevant!(infinite, "The distance was measured precisely.", close_to(f64::INFINITY));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "(1000002 is not close to 1000000 (rtol = 0.000001, atol = 0))")]
+fn not_close_far_from_zero() {
+    let distance = 1_000_002.0;
+    panic_irrelevant!(distance, "The distance was measured precisely.", close_to(1e6, rtol = 1e-6, atol = 0.0));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "(1 is not close to inf (rtol = 0.00001, atol = 0.00000001))")]
+fn finite_is_not_close_to_infinity() {
+    // The difference is infinite, but so is the relative tolerance.
+    let distance = 1.0;
+    panic_irrelevant!(distance, "The distance was measured precisely.", close_to(f64::INFINITY));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "(inf is not close to 1000000 (rtol = inf, atol = 0))")]
+fn infinity_is_not_close_to_finite() {
+    let distance = f64::INFINITY;
+    panic_irrelevant!(distance, "The distance was measured precisely.", close_to(1e6, rtol = f64::INFINITY, atol = 0.0));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "(-inf is not close to inf")]
+fn opposite_infinities_are_not_close() {
+    let distance = f64::NEG_INFINITY;
+    panic_irrelevant!(distance, "The distance was measured precisely.", close_to(f64::INFINITY));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "(NaN is not close to NaN")]
+fn nan_is_never_close() {
+    let distance = f64::NAN;
+    panic_irrelevant!(distance, "The distance was measured precisely.", close_to(f64::NAN));
+}
+#[test]
+fn elementwise_close() {
+    let output = vec![0.1 + 0.2, 1.0, -2.5];
+    panic_irrelevant!(output, "The kernel matches the reference.", elementwise_close([0.3, 1.0, -2.5], 1e-12));
+    let output: [f32; 2] = [0.5, 0.25];
+    let reference = vec![0.5f32, 0.2500001];
+    panic_irrelevant!(output, "The kernel matches the reference.", elementwise_close(reference, 1e-6));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(
+    expected = "Assumption violated: The kernel matches the reference. (the element at 1 is 1.001, more than 0.0001 away from 1)"
+)]
+fn not_elementwise_close() {
+    let output = [0.0, 1.001, 2.0, 5.0];
+    panic_irrelevant!(output, "The kernel matches the reference.", elementwise_close([0.0, 1.0, 2.0, 3.0], 1e-4));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "(2 elements, expected 3)")]
+fn elementwise_close_different_lengths() {
+    let output = [0.0, 1.0];
+    panic_irrelevant!(output, "The kernel matches the reference.", elementwise_close([0.0, 1.0, 2.0], 1e-4));
+}
+/// Ignores a latency, assuming it is not an outlier.
+fn record_latency(latency: f64) {
+    panic_irrelevant!(latency, "Latencies are only monitored in aggregate.", within_p99);
+}
+#[test]
+fn latencies_within_p99() {
+    for i in 0..2000 {
+        record_latency(f64::from(i % 10));
+    }
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(
+    expected = "Assumption violated: Latencies are only monitored in aggregate. (250 is above the 99th percentile of the previous values, 9)"
+)]
+fn latency_outlier() {
+    /// Uses a place of its own, so the history is not shared with [`latencies_within_p99`].
+    fn record_latency(latency: f64) {
+        panic_irrelevant!(latency, "Latencies are only monitored in aggregate.", within_p99);
+    }
+    // Outliers are only detected once there is enough history.
+    record_latency(250.0);
+    for i in 0..200 {
+        record_latency(f64::from(i % 10));
+    }
+    record_latency(250.0);
+}
@@ -0,0 +1,78 @@
+use irrelevant::*;
+use std::path::{Path, PathBuf};
+
+#[test]
+fn absolute_paths() {
+    let root = std::env::current_dir().unwrap();
+    panic_irrelevant!(root, "The working directory is always absolute.", absolute);
+    let config = PathBuf::from(if cfg!(windows) { r"C:\etc\app.toml" } else { "/etc/app.toml" });
+    panic_irrelevant!(config, "Config paths are resolved on startup.", absolute);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Config paths are resolved on startup. (\"config/app.toml\" is not absolute)")]
+fn relative_path() {
+    let config = Path::new("config/app.toml");
+    panic_irrelevant!(config, "Config paths are resolved on startup.", absolute);
+}
+/// Creates an empty directory for uploads, containing an `avatars` directory, unique to `name`.
+fn uploads(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("irrelevant-{}-{name}", std::process::id()));
+    std::fs::create_dir_all(root.join("avatars")).unwrap();
+    root
+}
+#[test]
+fn paths_under_root() {
+    let root = uploads("contained");
+    let avatar = Path::new("avatars");
+    panic_irrelevant!(avatar, "Uploads are only served from their directory.", under(root));
+    // Paths which don't exist yet are normalized.
+    let avatar = Path::new("avatars/../avatars/new.png");
+    panic_irrelevant!(avatar, "Uploads are only served from their directory.", under(root));
+    let avatar = root.join("avatars/./new.png");
+    panic_irrelevant!(avatar, "Uploads are only served from their directory.", under(root));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Uploads are only served from their directory. (\"avatars/../../passwd\" resolves to")]
+fn path_escapes_root() {
+    let root = uploads("escaping");
+    let avatar = Path::new("avatars/../../passwd");
+    panic_irrelevant!(avatar, "Uploads are only served from their directory.", under(root));
+}
+#[cfg(all(feature = "checks", unix))]
+#[test]
+#[should_panic(expected = "(\"/etc/passwd\" resolves to \"/etc/passwd\", outside of")]
+fn absolute_path_escapes_root() {
+    let root = uploads("absolute");
+    let avatar = Path::new("/etc/passwd");
+    panic_irrelevant!(avatar, "Uploads are only served from their directory.", under(root));
+}
+#[cfg(all(feature = "checks", unix))]
+#[test]
+#[should_panic(expected = "(\"link\" resolves to \"/etc\", outside of")]
+fn symlink_escapes_root() {
+    let root = uploads("symlink");
+    let _ = std::os::unix::fs::symlink("/etc", root.join("link"));
+    let avatar = Path::new("link");
+    panic_irrelevant!(avatar, "Uploads are only served from their directory.", under(root));
+}
+#[cfg(all(feature = "checks", unix))]
+#[test]
+#[should_panic(expected = "(\"link/new.conf\" resolves to \"/etc/new.conf\", outside of")]
+fn symlink_to_new_file_escapes_root() {
+    let root = uploads("symlink-new");
+    let _ = std::os::unix::fs::symlink("/etc", root.join("link"));
+    let avatar = Path::new("link/new.conf");
+    panic_irrelevant!(avatar, "Uploads are only served from their directory.", under(root));
+}
+#[cfg(all(feature = "checks", unix))]
+#[test]
+#[should_panic(expected = "(\"link/../../new.png\" resolves to \"/new.png\", outside of")]
+fn parent_of_symlink_escapes_root() {
+    let root = uploads("symlink-parent");
+    let _ = std::os::unix::fs::symlink("/etc", root.join("link"));
+    // `..` steps out of where the link points to, not back into the root.
+    let avatar = Path::new("link/../../new.png");
+    panic_irrelevant!(avatar, "Uploads are only served from their directory.", under(root));
+}
@@ -0,0 +1,77 @@
+use irrelevant::*;
+use std::ptr::NonNull;
+
+#[test]
+fn in_range_pointers() {
+    let arena = [0u64; 8];
+    let range = arena.as_ptr_range();
+    let ptr = &arena[3] as *const u64;
+    panic_irrelevant!(ptr, "Nodes are allocated in the arena.", in_range(range.start, range.end));
+    let ptr = &arena[0];
+    panic_irrelevant!(ptr, "Nodes are allocated in the arena.", in_range(range.start, range.end));
+    let mut arena = [0u8; 4];
+    let range = arena.as_mut_ptr_range();
+    let ptr = range.start.wrapping_add(3);
+    panic_irrelevant!(ptr, "Nodes are allocated in the arena.", in_range(range.start, range.end));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Nodes are allocated in the arena. (0x")]
+fn out_of_range_pointer() {
+    let arena = [0u64; 8];
+    let other = [0u64; 8];
+    let range = arena.as_ptr_range();
+    let ptr = other.as_ptr();
+    panic_irrelevant!(ptr, "Nodes are allocated in the arena.", in_range(range.start, range.end));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Nodes are allocated in the arena.")]
+fn one_past_the_end_pointer() {
+    let arena = [0u64; 8];
+    let range = arena.as_ptr_range();
+    let ptr = range.end;
+    panic_irrelevant!(ptr, "Nodes are allocated in the arena.", in_range(range.start, range.end));
+}
+#[test]
+fn dangling_pointers() {
+    let ptr = NonNull::<()>::dangling().as_ptr();
+    panic_irrelevant!(ptr, "Zero-sized values are never allocated.", dangling);
+    let ptr = NonNull::<u64>::dangling().as_ptr().cast_const();
+    panic_irrelevant!(ptr, "Zero-sized values are never allocated.", dangling);
+    let empty: Vec<u32> = Vec::new();
+    let ptr = empty.as_ptr();
+    panic_irrelevant!(ptr, "Empty vectors are never allocated.", dangling);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Empty vectors are never allocated. (0x")]
+fn real_pointer() {
+    let allocated: Vec<u32> = vec![1];
+    let ptr = allocated.as_ptr();
+    panic_irrelevant!(ptr, "Empty vectors are never allocated.", dangling);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "is not the dangling pointer 0x8")]
+fn misaligned_dangling() {
+    let ptr = NonNull::<u8>::dangling().as_ptr().cast::<u64>();
+    panic_irrelevant!(ptr, "Zero-sized values are never allocated.", dangling);
+}
+#[test]
+fn atomic_aligned_pointers() {
+    let counters = [0u64; 4];
+    let ptr = &counters[1] as *const u64;
+    panic_irrelevant!(ptr, "Counters are shared with other threads.", atomic_aligned::<u64>());
+    let flag = 0u8;
+    let ptr = &flag as *const u8;
+    panic_irrelevant!(ptr, "Flags are shared with other threads.", atomic_aligned::<bool>());
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "is not aligned to 8 bytes")]
+fn atomic_misaligned_pointer() {
+    let buffer = [0u64; 2];
+    let ptr = buffer.as_ptr().cast::<u8>().wrapping_add(4);
+    panic_irrelevant!(ptr, "Counters are shared with other threads.", atomic_aligned::<u64>());
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "std")]
+use irrelevant::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct CountingPool {
+    active: AtomicUsize,
+}
+struct OpaquePool {
+    busy: bool,
+}
+fn register() {
+    register_pool::<CountingPool>(
+        |pool| pool.active.load(Ordering::Relaxed) == 0,
+        Some(|pool| pool.active.load(Ordering::Relaxed)),
+    );
+    register_pool::<OpaquePool>(|pool| !pool.busy, None);
+}
+#[test]
+fn idle_pools() {
+    register();
+    let pool = CountingPool { active: AtomicUsize::new(0) };
+    panic_irrelevant!(pool, "All the jobs were joined before shutdown.", idle);
+    let pool = OpaquePool { busy: false };
+    panic_irrelevant!(pool, "All the jobs were joined before shutdown.", idle);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: All the jobs were joined before shutdown. (3 tasks are running)")]
+fn busy_pool() {
+    register();
+    let pool = CountingPool { active: AtomicUsize::new(3) };
+    panic_irrelevant!(pool, "All the jobs were joined before shutdown.", idle);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "(tasks are running)")]
+fn busy_pool_without_count() {
+    register();
+    let pool = OpaquePool { busy: true };
+    panic_irrelevant!(pool, "All the jobs were joined before shutdown.", idle);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "(pool::unregistered_pool::UnregisteredPool was never registered as a pool)")]
+fn unregistered_pool() {
+    struct UnregisteredPool;
+    let pool = UnregisteredPool;
+    panic_irrelevant!(pool, "All the jobs were joined before shutdown.", idle);
+}
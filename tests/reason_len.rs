@@ -0,0 +1,27 @@
+use std::path::Path;
+use std::process::{Command, Output};
+
+/// Builds the crate in `tests/reason_len_crate`, with reasons limited to `max_len` bytes.
+fn build_with_limit(max_len: &str) -> Output {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    Command::new(env!("CARGO"))
+        .args(["build", "--offline", "--quiet", "--manifest-path"])
+        .arg(manifest_dir.join("tests/reason_len_crate/Cargo.toml"))
+        .arg("--target-dir")
+        .arg(manifest_dir.join("target/reason_len"))
+        .env("IRRELEVANT_MAX_REASON_LEN", max_len)
+        .output()
+        .unwrap()
+}
+// The builds share a target directory, so they run one after another.
+#[test]
+fn reasons_are_limited() {
+    let output = build_with_limit("64");
+    assert!(output.status.success(), "a short enough reason was rejected");
+    let output = build_with_limit("16");
+    assert!(!output.status.success(), "a too long reason was accepted");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("error[E0080]"), "{stderr}");
+    assert!(stderr.contains("The reason is longer than IRRELEVANT_MAX_REASON_LEN: Responses are compressed by the proxy."));
+    assert!(stderr.contains("The reason is longer than IRRELEVANT_MAX_REASON_LEN: Responses are compressed by the {}."));
+}
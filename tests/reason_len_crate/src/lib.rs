@@ -0,0 +1,12 @@
+//! A crate ignoring values with 38 and 35 byte reasons, to check their length is limited. Built by `tests/reason_len.rs`.
+use irrelevant::*;
+
+pub fn serve(request: &str, compressed: bool) -> usize {
+    irrelevant!(compressed, "Responses are compressed by the proxy.", !compressed);
+    request.len()
+}
+
+pub fn forward(request: &str, proxy: &str, compressed: bool) -> usize {
+    irrelevant!(compressed, "Responses are compressed by the {}.", proxy; !compressed);
+    request.len()
+}
@@ -0,0 +1,68 @@
+use irrelevant::*;
+use std::process::Command;
+
+const CHILD_ENV: &str = "IRRELEVANT_RECURSION_CHILD";
+const MAX_DEPTH: usize = 8;
+
+fn depth_of(n: usize, depth: usize) -> usize {
+    let next = depth + 1;
+    panic_irrelevant!(depth, "The input is never nested too deep.", <= MAX_DEPTH);
+    if n == 0 {
+        0
+    } else {
+        1 + depth_of(n - 1, next)
+    }
+}
+fn guarded(n: usize) -> usize {
+    recursion_guard!(MAX_DEPTH, "The input is never nested too deep.");
+    if n == 0 {
+        0
+    } else {
+        1 + guarded(n - 1)
+    }
+}
+#[test]
+fn explicit_depth_shallow() {
+    assert_eq!(depth_of(MAX_DEPTH, 0), MAX_DEPTH);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: The input is never nested too deep. (9 is larger than 8)")]
+fn explicit_depth_deep() {
+    depth_of(MAX_DEPTH + 1, 0);
+}
+/// Recurses as requested by [`run_child`], so the violations can be read from its output.
+#[test]
+fn child() {
+    if let Some(n) = std::env::var_os(CHILD_ENV) {
+        let n: usize = n.to_str().unwrap().parse().unwrap();
+        // Repeated calls must not accumulate depth.
+        for _ in 0..3 {
+            assert_eq!(guarded(n), n);
+        }
+    }
+}
+/// Runs [`child`], and returns the violations it logged. With the `build-script` feature, they go to `stdout`.
+fn run_child(n: usize) -> String {
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", "child", "--nocapture"])
+        .env(CHILD_ENV, n.to_string())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let log = if cfg!(feature = "build-script") { output.stdout } else { output.stderr };
+    String::from_utf8(log).unwrap()
+}
+#[test]
+fn guard_shallow() {
+    let log = run_child(MAX_DEPTH - 1);
+    assert!(!log.contains("Assumption violated"), "{log}");
+}
+#[cfg(feature = "checks")]
+#[test]
+fn guard_deep() {
+    let log = run_child(MAX_DEPTH + 1);
+    // The calls at depth 9 and 10 violate the limit, in every one of the 3 runs.
+    assert_eq!(log.matches("Assumption violated: The input is never nested too deep.").count(), 6);
+    assert!(log.contains("(the recursion is 10 calls deep)"), "{log}");
+}
@@ -0,0 +1,68 @@
+use irrelevant::*;
+#[cfg(feature = "checks")]
+use std::mem::ManuallyDrop;
+use std::rc::Rc;
+#[cfg(feature = "checks")]
+use std::sync::Mutex;
+
+/// A pool of connections, where each handle keeps the pool busy until it is dropped.
+struct Pool(Rc<()>);
+impl Pool {
+    fn connect(&self) -> Rc<()> {
+        self.0.clone()
+    }
+    fn idle(&self) -> bool {
+        Rc::strong_count(&self.0) == 1
+    }
+}
+#[test]
+fn released_guard() {
+    let pool = Pool(Rc::new(()));
+    {
+        let connection = pool.connect();
+        scoped_irrelevant!(connection, "The connection only reserves a slot.", || pool.idle());
+        assert!(!pool.idle());
+    }
+    assert!(pool.idle());
+    assert_eq!(last_violation(), None);
+}
+#[cfg(feature = "checks")]
+#[test]
+fn leaked_guard() {
+    let pool = Pool(Rc::new(()));
+    let mut leaked = Vec::new();
+    {
+        let connection = pool.connect();
+        leaked.push(connection.clone());
+        scoped_irrelevant!(connection, "The connection only reserves a slot.", || pool.idle());
+    }
+    assert!(!pool.idle());
+    let violation = last_violation().unwrap();
+    assert_eq!(violation.reason, "The connection only reserves a slot.");
+    assert_eq!(violation.location.line, 37);
+}
+#[cfg(feature = "checks")]
+#[test]
+fn forgotten_guard() {
+    let counter = Mutex::new(0);
+    {
+        let guard = ManuallyDrop::new(counter.lock().unwrap());
+        scoped_irrelevant!(guard, "The lock only stops others from counting.", || counter.try_lock().is_ok());
+    }
+    assert!(counter.try_lock().is_err());
+    let violation = last_violation().unwrap();
+    assert_eq!(violation.reason, "The lock only stops others from counting.");
+    assert_eq!(violation.location.line, 50);
+}
+#[test]
+fn moved_token() {
+    let pool = Pool(Rc::new(()));
+    let token = {
+        let connection = pool.connect();
+        scoped_irrelevant!(let token = connection, "The connection only reserves a slot.", || pool.idle());
+        token
+    };
+    assert!(pool.idle());
+    drop(token);
+    assert_eq!(last_violation(), None);
+}
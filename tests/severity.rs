@@ -0,0 +1,73 @@
+#![cfg(feature = "checks")]
+use irrelevant::*;
+use std::process::Command;
+use std::sync::Mutex;
+
+static HANDLED: Mutex<Vec<(Option<Severity>, String)>> = Mutex::new(Vec::new());
+fn collect(violation: &ViolationInfo) {
+    HANDLED.lock().unwrap().push((violation.severity, violation.to_string()));
+}
+// The handler is global, so all the scenarios share a single test.
+#[test]
+fn severities_go_to_the_handler() {
+    set_violation_handler(collect);
+    let sauces = ["ketchup"];
+    irrelevant!(info: sauces, "No sauces should come with a drink!", is_empty);
+    let (ice, cubes, more_cubes, most_cubes) = (3, 3, 3, 3);
+    irrelevant!(warn: ice, "Hot drinks come with at most 2 cubes.", ice <= 2);
+    irrelevant!(error: cubes, "Hot drinks come without ice.", cubes == 0);
+    irrelevant!(error: more_cubes + 1, "Hot drinks come with at most 1 cube.", < 2);
+    // Violations without a severity are reported as before.
+    irrelevant!(more_cubes, "Hot drinks come without ice.", more_cubes == 0);
+    // Satisfied assumptions are not reported, regardless of their severity.
+    irrelevant!(error: most_cubes, "Drinks come with at most 5 cubes.", most_cubes <= 5);
+    let straw = ();
+    irrelevant!(error: straw, "Straws are handed out at the counter.");
+    assert_eq!(
+        *HANDLED.lock().unwrap(),
+        [
+            (
+                Some(Severity::Info),
+                "info: [tests/severity.rs:15:5] Assumption violated: No sauces should come with a drink!".into()
+            ),
+            (
+                Some(Severity::Warning),
+                "warning: [tests/severity.rs:17:5] Assumption violated: Hot drinks come with at most 2 cubes.".into()
+            ),
+            (
+                Some(Severity::Error),
+                "error: [tests/severity.rs:18:5] Assumption violated: Hot drinks come without ice.".into()
+            ),
+            (
+                Some(Severity::Error),
+                "error: [tests/severity.rs:19:5] Assumption violated: Hot drinks come with at most 1 cube. (4 is not smaller than 2)"
+                    .into()
+            ),
+            (None, "[tests/severity.rs:21:5] Assumption violated: Hot drinks come without ice.".into()),
+        ]
+    );
+}
+/// Violates an assumption with the `error` severity, after enabling aborting. Only run by `errors_abort`.
+#[test]
+#[ignore]
+fn abort_on_error() {
+    set_abort_on_error(true);
+    let (ice, cubes) = (3, 3);
+    irrelevant!(warn: ice, "Hot drinks come with at most 2 cubes.", ice <= 2);
+    irrelevant!(error: cubes, "Hot drinks come without ice.", cubes == 0);
+    unreachable!("the process should have aborted");
+}
+#[test]
+fn errors_abort() {
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args(["abort_on_error", "--exact", "--ignored", "--nocapture"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    // Build scripts report violations to `stdout`, so both are searched.
+    let reported = String::from_utf8(output.stderr).unwrap() + &String::from_utf8(output.stdout).unwrap();
+    let warning = "warning: [tests/severity.rs:56:5] Assumption violated: Hot drinks come with at most 2 cubes.";
+    assert!(reported.contains(warning), "{reported}");
+    assert!(reported.contains("error: [tests/severity.rs:57:5] Assumption violated: Hot drinks come without ice."), "{reported}");
+    assert!(!reported.contains("the process should have aborted"), "{reported}");
+}
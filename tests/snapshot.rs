@@ -0,0 +1,63 @@
+#![cfg(feature = "snapshot")]
+use irrelevant::*;
+use std::path::PathBuf;
+
+// The fields are only read through `Debug`.
+#[allow(dead_code)]
+#[derive(Debug)]
+struct Config {
+    retries: u32,
+    verbose: bool,
+}
+/// Returns a fresh snapshot path, unique to this test.
+fn snapshot_path(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("irrelevant-snapshots-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(test);
+    let _ = std::fs::remove_file(&path);
+    path
+}
+#[test]
+fn snapshot_created_then_matched() {
+    let path = snapshot_path("matched");
+    let config = Config { retries: 3, verbose: false };
+    panic_irrelevant!(config, "The defaults never change.", snapshot &path);
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        "Config {\n    retries: 3,\n    verbose: false,\n}"
+    );
+    let config = Config { retries: 3, verbose: false };
+    panic_irrelevant!(config, "The defaults never change.", snapshot &path);
+}
+#[test]
+#[should_panic(expected = "Assumption violated: The defaults never change. (the value differs from its snapshot")]
+fn snapshot_mismatch() {
+    let path = snapshot_path("mismatch");
+    let config = Config { retries: 3, verbose: false };
+    panic_irrelevant!(config, "The defaults never change.", snapshot &path);
+    let config = Config { retries: 5, verbose: false };
+    panic_irrelevant!(config, "The defaults never change.", snapshot &path);
+}
+#[cfg(feature = "pretty")]
+#[test]
+fn snapshot_mismatch_diff() {
+    let path = snapshot_path("diff");
+    let config = Config { retries: 3, verbose: false };
+    panic_irrelevant!(config, "The defaults never change.", snapshot &path);
+    let config = Config { retries: 5, verbose: false };
+    let result = std::panic::catch_unwind(|| {
+        panic_irrelevant!(config, "The defaults never change.", snapshot &path);
+    });
+    let message = *result.unwrap_err().downcast::<String>().unwrap();
+    assert!(
+        message.contains(concat!(
+            "the value differs from its snapshot:\n",
+            "  Config {\n",
+            "\x1b[31m-     retries: 3,\x1b[0m\n",
+            "\x1b[32m+     retries: 5,\x1b[0m\n",
+            "      verbose: false,\n",
+            "  }\n",
+        )),
+        "{message}"
+    );
+}
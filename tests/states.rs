@@ -0,0 +1,35 @@
+use irrelevant::*;
+
+#[derive(Debug, Default, PartialEq)]
+#[cfg_attr(not(feature = "checks"), allow(dead_code))]
+enum Connection {
+    #[default]
+    Idle,
+    Connecting {
+        attempt: u32,
+    },
+    Connected(u16),
+}
+#[test]
+fn default_state() {
+    let state = Connection::Idle;
+    panic_irrelevant!(state, "Connections are configured before they are opened.", is_default);
+    let retries = 0;
+    panic_irrelevant!(retries, "Nothing was sent yet.", is_default);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(
+    expected = "Assumption violated: Connections are configured before they are opened. (the variant is Connected, not the default Idle)"
+)]
+fn tuple_state() {
+    let state = Connection::Connected(8080);
+    panic_irrelevant!(state, "Connections are configured before they are opened.", is_default);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "(the variant is Connecting, not the default Idle)")]
+fn struct_state() {
+    let state = Connection::Connecting { attempt: 2 };
+    panic_irrelevant!(state, "Connections are configured before they are opened.", is_default);
+}
@@ -0,0 +1,107 @@
+use irrelevant::*;
+
+#[test]
+fn uuid_valid() {
+    let id = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+    panic_irrelevant!(id, "Ids are generated by the database.", is_uuid);
+    let id = String::from("67E55044-10B1-426F-9247-BB680E5FE0C8");
+    panic_irrelevant!(id, "Ids are generated by the database.", is_uuid);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Ids are generated by the database. (\"67e55044-10b1426f-9247-bb680e5fe0c8a\" is not a UUID)")]
+fn uuid_misplaced_hyphen() {
+    let id = "67e55044-10b1426f-9247-bb680e5fe0c8a";
+    panic_irrelevant!(id, "Ids are generated by the database.", is_uuid);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "is not a UUID")]
+fn uuid_not_hex() {
+    let id = "67e55044-10b1-426f-9247-bb680e5fe0cg";
+    panic_irrelevant!(id, "Ids are generated by the database.", is_uuid);
+}
+#[test]
+fn hex_valid() {
+    let hash = "deadBEEF0123";
+    panic_irrelevant!(hash, "Hashes are always hex encoded.", is_hex);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "(\"0xdeadbeef\" is not hexadecimal)")]
+fn hex_prefixed() {
+    let hash = "0xdeadbeef";
+    panic_irrelevant!(hash, "Hashes are always hex encoded.", is_hex);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "(\"\" is not hexadecimal)")]
+fn hex_empty() {
+    let hash = "";
+    panic_irrelevant!(hash, "Hashes are always hex encoded.", is_hex);
+}
+#[test]
+fn trimmed_string() {
+    let name = "Alice";
+    panic_irrelevant!(name, "Names are trimmed by the form.", trimmed);
+    let name = String::new();
+    panic_irrelevant!(name, "Names are trimmed by the form.", trimmed);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Names are trimmed by the form. (\" Alice\\n\" is not trimmed)")]
+fn untrimmed_string() {
+    let name = String::from(" Alice\n");
+    panic_irrelevant!(name, "Names are trimmed by the form.", trimmed);
+}
+const ROUTES: [&str; 2] = ["/api", "/v1"];
+#[test]
+fn starts_with_allowed_prefix() {
+    let path = "/api/users";
+    panic_irrelevant!(path, "Only API routes reach this handler.", starts_with_any ["/api", "/v1"]);
+    let path = String::from("/v1/users");
+    panic_irrelevant!(path, "Only API routes reach this handler.", starts_with_any ROUTES);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Only API routes reach this handler. (\"/static/logo.png\" starts with none of the prefixes)")]
+fn starts_with_no_allowed_prefix() {
+    let path = "/static/logo.png";
+    panic_irrelevant!(path, "Only API routes reach this handler.", starts_with_any ["/api", "/v1"]);
+}
+#[test]
+fn inline_capable_strings() {
+    let key = "session:42";
+    panic_irrelevant!(key, "Cache keys are short.", inline_capable(22));
+    let key = String::from("exactly-22-bytes-long!");
+    panic_irrelevant!(key, "Cache keys are short.", inline_capable(22));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Cache keys are short. (23 bytes long, at most 22 fit inline)")]
+fn too_long_for_inline() {
+    let key = "exactly-23-bytes-long!!";
+    panic_irrelevant!(key, "Cache keys are short.", inline_capable(22));
+}
+#[test]
+fn valid_utf16() {
+    let title: Vec<u16> = "Résumé 📄".encode_utf16().collect();
+    panic_irrelevant!(title, "Window titles are set by the application.", valid_utf16);
+    let empty: &[u16] = &[];
+    panic_irrelevant!(empty, "Window titles are set by the application.", valid_utf16);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Window titles are set by the application. (found the unpaired surrogate 0xd83d at 3)")]
+fn lone_high_surrogate() {
+    // "📄" is encoded as 0xd83d 0xdcc4, so the high surrogate is left unpaired.
+    let title: &[u16] = &[0x0044, 0x006f, 0x0063, 0xd83d, 0x0020];
+    panic_irrelevant!(title, "Window titles are set by the application.", valid_utf16);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "(found the unpaired surrogate 0xdcc4 at 4)")]
+fn lone_low_surrogate() {
+    let title: [u16; 5] = [0xd83d, 0xdcc4, 0x0020, 0x0041, 0xdcc4];
+    panic_irrelevant!(title, "Window titles are set by the application.", valid_utf16);
+}
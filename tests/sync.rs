@@ -0,0 +1,143 @@
+use irrelevant::*;
+#[cfg(feature = "checks")]
+use irrelevant::checks::ConcurrentQueue;
+use std::collections::VecDeque;
+use std::sync::{mpsc, Arc, Mutex, RwLock};
+
+#[test]
+fn last_sender_single() {
+    let (tx, _rx) = mpsc::channel::<u8>();
+    let tx = Arc::new(tx);
+    panic_irrelevant!(tx, "The only sender is dropped on shutdown.", last_sender);
+}
+#[test]
+fn last_sender_sync_channel() {
+    let (tx, _rx) = mpsc::sync_channel::<u8>(1);
+    let tx = Arc::new(tx);
+    panic_irrelevant!(tx, "The only sender is dropped on shutdown.", last_sender);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Shutdown needs the last sender! (3 senders are alive)")]
+fn last_sender_multiple() {
+    let (tx, _rx) = mpsc::channel::<u8>();
+    let tx = Arc::new(tx);
+    let _workers = [tx.clone(), tx.clone()];
+    panic_irrelevant!(tx, "Shutdown needs the last sender!", last_sender);
+}
+/// A sender of a custom channel, which knows whether it is the last one on its own.
+#[cfg(feature = "checks")]
+struct Handle {
+    last: bool,
+}
+#[cfg(feature = "checks")]
+impl Handle {
+    fn last_sender(&self) -> bool {
+        self.last
+    }
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Shutdown needs the last sender!")]
+fn last_sender_method_takes_precedence() {
+    let handle = Handle { last: false };
+    panic_irrelevant!(handle, "Shutdown needs the last sender!", last_sender);
+}
+#[test]
+fn empty_queue() {
+    let queue = Arc::new(Mutex::new(VecDeque::<u8>::new()));
+    panic_irrelevant!(queue, "All the workers finished.", empty);
+    let stack = RwLock::new(Vec::<u8>::new());
+    panic_irrelevant!(stack, "All the workers finished.", empty);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: All the workers finished. (2 items remain)")]
+fn empty_queue_with_items() {
+    let queue = Arc::new(Mutex::new(VecDeque::from([1, 2])));
+    panic_irrelevant!(queue, "All the workers finished.", empty);
+}
+/// A queue which can't cheaply tell its length.
+#[cfg(feature = "checks")]
+struct LockFreeStack(bool);
+#[cfg(feature = "checks")]
+impl ConcurrentQueue for LockFreeStack {
+    fn is_empty(&self) -> bool {
+        self.0
+    }
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: All the workers finished. (items remain)")]
+fn empty_user_queue_with_items() {
+    let stack = LockFreeStack(true);
+    panic_irrelevant!(stack, "All the workers finished.", empty);
+    let stack = LockFreeStack(false);
+    panic_irrelevant!(stack, "All the workers finished.", empty);
+}
+/// Poisons `lock`, by panicking on another thread while holding it.
+fn poison<T: Send>(lock: &Mutex<T>) {
+    std::thread::scope(|scope| {
+        let _ = scope
+            .spawn(|| {
+                let _guard = lock.lock().unwrap();
+                panic!("The worker crashed.");
+            })
+            .join();
+    });
+    assert!(lock.is_poisoned());
+}
+#[test]
+fn empty_poisoned_queue() {
+    let queue = Mutex::new(VecDeque::<u8>::new());
+    poison(&queue);
+    panic_irrelevant!(queue, "The crashed worker took no items.", empty);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: The crashed worker took no items. (1 items remain)")]
+fn empty_poisoned_queue_with_items() {
+    let queue = Mutex::new(VecDeque::from([1]));
+    poison(&queue);
+    panic_irrelevant!(queue, "The crashed worker took no items.", empty);
+}
+#[test]
+fn unique_arc() {
+    let shared = Arc::new(vec![1, 2, 3]);
+    let worker = shared.clone();
+    drop(worker);
+    panic_irrelevant!(shared, "All the workers are done, so the data can be taken back.", unique);
+    let local = std::rc::Rc::new(5);
+    panic_irrelevant!(local, "The value was never shared.", unique);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: All the workers are done, so the data can be taken back. (2 strong references exist)")]
+fn shared_arc() {
+    let shared = Arc::new(vec![1, 2, 3]);
+    let _worker = shared.clone();
+    panic_irrelevant!(shared, "All the workers are done, so the data can be taken back.", unique);
+}
+#[test]
+fn uncontended_lock() {
+    let cache = Arc::new(Mutex::new(0));
+    panic_irrelevant!(cache, "Only this thread uses the cache at startup.", uncontended);
+    let config = RwLock::new(0);
+    panic_irrelevant!(config, "The config is only written at startup.", uncontended);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Only this thread uses the cache at startup. (the lock is held elsewhere)")]
+fn contended_lock() {
+    let cache = Arc::new(Mutex::new(0));
+    let _held = cache.lock().unwrap();
+    panic_irrelevant!(cache, "Only this thread uses the cache at startup.", uncontended);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "(the lock is held elsewhere)")]
+fn contended_rwlock_reader() {
+    let config = RwLock::new(0);
+    let _reader = config.read().unwrap();
+    panic_irrelevant!(config, "The config is only written at startup.", uncontended);
+}
@@ -0,0 +1,54 @@
+use irrelevant::*;
+use std::cell::Cell;
+
+struct Connection<'a> {
+    closed: &'a Cell<u32>,
+}
+impl Drop for Connection<'_> {
+    fn drop(&mut self) {
+        self.closed.set(self.closed.get() + 1);
+    }
+}
+fn close(connection: Connection) {
+    drop(connection);
+}
+#[test]
+fn take_moves_the_value() {
+    let closed = Cell::new(0);
+    let connection = Connection { closed: &closed };
+    irrelevant_take!(let taken = connection, "The connection is only closed here.");
+    let _: ExplicitlyIgnoredValue = connection;
+    assert_eq!(closed.get(), 0);
+    close(taken);
+    assert_eq!(closed.get(), 1);
+}
+#[test]
+#[cfg(all(feature = "checks", feature = "std"))]
+fn take_checks_assumptions() {
+    let pending = vec![1, 2];
+    irrelevant_take!(let flushed = pending, "Pending writes are flushed before closing.", pending.is_empty());
+    assert_eq!(last_violation().unwrap().reason, "Pending writes are flushed before closing.");
+    let _: ExplicitlyIgnoredValue = pending;
+    assert_eq!(flushed, [1, 2]);
+}
+#[test]
+fn take_named_assumption() {
+    let closed = Cell::new(0);
+    let connections = vec![Connection { closed: &closed }];
+    irrelevant_take!(let taken = connections, "Only a single connection is ever open.", drained);
+    assert_eq!(taken.len(), 1);
+    drop(taken);
+    assert_eq!(closed.get(), 1);
+}
+#[test]
+#[cfg(all(feature = "checks", feature = "std"))]
+fn take_as_expression() {
+    let closed = Cell::new(0);
+    let connection = Connection { closed: &closed };
+    close(irrelevant_take!(connection, "The connection is only closed here."));
+    assert_eq!(closed.get(), 1);
+    let pending = vec![1, 2];
+    let flushed = irrelevant_take!(pending, "Pending writes are flushed before closing.", drained);
+    assert_eq!(last_violation().unwrap().reason, "Pending writes are flushed before closing.");
+    assert_eq!(flushed, [1, 2]);
+}
@@ -0,0 +1,23 @@
+use irrelevant::*;
+
+fn render(text: &str, style: &str, width: usize) -> String {
+    temp_irrelevant!(style, "Styles are not supported by the renderer yet.");
+    temp_irrelevant!(width, "Wrapping is not supported by the renderer yet.", width > 0);
+    text.to_owned()
+}
+fn parse(text: &str, strict: bool) -> &str {
+    irrelevant!(strict, "The parser is always lenient.");
+    text
+}
+#[test]
+fn lists_temporary_ignores() {
+    for _ in 0..3 {
+        render("Hello", "bold", 80);
+        parse("Hello", true);
+    }
+    let ignores = temporary_ignores();
+    assert_eq!(ignores.len(), 2);
+    assert!(ignores.iter().all(|location| location.file.ends_with("temporary.rs")));
+    assert_eq!(ignores[0].line, 4);
+    assert_eq!(ignores[1].line, 5);
+}
@@ -0,0 +1,41 @@
+use irrelevant::checks::Terminal;
+use irrelevant::*;
+
+struct MockStream {
+    terminal: bool,
+}
+impl Terminal for MockStream {
+    fn is_terminal(&self) -> bool {
+        self.terminal
+    }
+}
+#[test]
+fn piped() {
+    let output = MockStream { terminal: false };
+    panic_irrelevant!(output, "The output is always piped into the pager.", not_a_tty);
+    let input = MockStream { terminal: true };
+    panic_irrelevant!(input, "Passwords are only read interactively.", a_tty);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: The output is always piped into the pager. (it is a terminal)")]
+fn not_piped() {
+    let output = MockStream { terminal: true };
+    panic_irrelevant!(output, "The output is always piped into the pager.", not_a_tty);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Passwords are only read interactively. (it is not a terminal)")]
+fn not_interactive() {
+    let input = MockStream { terminal: false };
+    panic_irrelevant!(input, "Passwords are only read interactively.", a_tty);
+}
+#[test]
+fn standard_streams() {
+    // Whether the tests run in a terminal depends on how they are started, so this only checks the streams are
+    // supported, and agree with `IsTerminal`.
+    let stdout = std::io::stdout();
+    let expected = std::io::IsTerminal::is_terminal(&stdout);
+    assert_eq!(Terminal::is_terminal(&stdout), expected);
+    irrelevant!(stdout, "The output is always piped into the pager.", not_a_tty);
+}
@@ -0,0 +1,28 @@
+use irrelevant::*;
+use std::time::{Duration, SystemTime};
+
+/// 2020-01-01T00:00:00Z.
+const MIN_SECS: u64 = 1_577_836_800;
+#[test]
+fn recent_timestamps() {
+    let now = SystemTime::now();
+    panic_irrelevant!(now, "The clock is synchronized on boot.", after(MIN_SECS));
+    let created = Duration::from_secs(MIN_SECS);
+    panic_irrelevant!(created, "Accounts were only created after the launch.", after(MIN_SECS));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(
+    expected = "Assumption violated: The clock is synchronized on boot. (86400 seconds since the epoch, expected at least 1577836800)"
+)]
+fn old_timestamp() {
+    let now = SystemTime::UNIX_EPOCH + Duration::from_secs(86_400);
+    panic_irrelevant!(now, "The clock is synchronized on boot.", after(MIN_SECS));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "(before the epoch, expected at least 1577836800 seconds after it)")]
+fn timestamp_before_epoch() {
+    let now = SystemTime::UNIX_EPOCH - Duration::from_secs(1);
+    panic_irrelevant!(now, "The clock is synchronized on boot.", after(MIN_SECS));
+}
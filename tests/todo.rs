@@ -0,0 +1,41 @@
+#![allow(deprecated)]
+#[cfg(feature = "checks")]
+use irrelevant::*;
+use std::path::Path;
+use std::process::Command;
+
+#[cfg(feature = "checks")]
+#[test]
+fn todo_ignores() {
+    let style = "bold";
+    todo_irrelevant!(style, "Styles are not supported yet.");
+    let width = 0;
+    todo_irrelevant!(width, "Wrapping is not supported yet.", > 0);
+    assert_eq!(last_violation().unwrap().reason, "Wrapping is not supported yet.");
+}
+#[test]
+fn only_reminders_are_warned_about() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let output = Command::new(env!("CARGO"))
+        .args(["rustc", "--offline", "--lib", "--manifest-path"])
+        .arg(manifest_dir.join("tests/todo_crate/Cargo.toml"))
+        .arg("--target-dir")
+        .arg(manifest_dir.join("target/todo"))
+        // Some lints which are allowed by default, to check the expanded code doesn't trigger them either.
+        .args(["--", "-W", "unused", "-W", "rust_2018_idioms", "-W", "future_incompatible", "-W", "let_underscore"])
+        .args(["-W", "nonstandard_style", "-W", "unused_qualifications", "-W", "unused_results"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(output.status.success(), "the todo crate failed to build: {stderr}");
+    let warnings: Vec<_> = stderr.lines().filter(|line| line.starts_with("warning: ")).collect();
+    assert_eq!(
+        warnings,
+        [
+            "warning: use of deprecated function `render::todo_irrelevant`: Styles are not supported yet.",
+            "warning: use of deprecated function `wrap::todo_irrelevant`: Wrapping is not supported yet.",
+            "warning: `irrelevant-todo` (lib) generated 2 warnings",
+        ],
+        "{stderr}"
+    );
+}
@@ -0,0 +1,11 @@
+//! A crate using `todo_irrelevant`, to check it only warns about the reminders. Built by `tests/todo.rs`.
+use irrelevant::*;
+
+pub fn render(text: &str, style: &str) -> String {
+    todo_irrelevant!(style, "Styles are not supported yet.");
+    text.to_owned()
+}
+pub fn wrap(text: &str, width: usize) -> String {
+    todo_irrelevant!(width, "Wrapping is not supported yet.", > 0);
+    text.to_owned()
+}
@@ -0,0 +1,49 @@
+use irrelevant::*;
+
+fn parse(header: &[u8], padding: &[u8]) -> Result<usize, IgnoreViolation> {
+    try_irrelevant!(padding, "Headers are never padded.", is_empty);
+    Ok(header.len())
+}
+#[derive(Debug)]
+#[cfg_attr(not(feature = "checks"), allow(dead_code))]
+enum ParseError {
+    Violation(IgnoreViolation),
+}
+impl From<IgnoreViolation> for ParseError {
+    fn from(violation: IgnoreViolation) -> Self {
+        Self::Violation(violation)
+    }
+}
+fn parse_version(version: u8, flags: u8) -> Result<u8, ParseError> {
+    try_irrelevant!(flags, "Version {} has no flags.", version; == 0);
+    Ok(version)
+}
+#[test]
+#[cfg(feature = "std")]
+fn assumption_holds() {
+    assert_eq!(parse(b"GET", b""), Ok(3));
+    assert_eq!(parse_version(1, 0).unwrap(), 1);
+    assert_eq!(last_violation(), None);
+}
+#[test]
+#[cfg(all(feature = "checks", feature = "std"))]
+fn violations_are_returned() {
+    let violation = parse(b"GET", b"  ").unwrap_err();
+    assert_eq!(
+        violation,
+        IgnoreViolation {
+            file: "tests/try_irrelevant.rs",
+            line: 4,
+            column: 5,
+            reason: "Headers are never padded.",
+        }
+    );
+    assert_eq!(violation.to_string(), "[tests/try_irrelevant.rs:4:5] Assumption violated: Headers are never padded.");
+    assert_eq!(last_violation().unwrap().location.line, 4);
+}
+#[cfg(feature = "checks")]
+#[test]
+fn violations_are_converted() {
+    let ParseError::Violation(violation) = parse_version(2, 1).unwrap_err();
+    assert_eq!(violation.reason, "Version {} has no flags.");
+}
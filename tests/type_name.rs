@@ -0,0 +1,25 @@
+use irrelevant::*;
+
+struct Celsius;
+/// Ignores the unit of a reading, which is always expected to be in degrees Celsius.
+fn record<T>(unit: T) {
+    panic_irrelevant!(unit, "Only the Celsius scale is supported.", type_name == "type_name::Celsius");
+}
+#[test]
+fn type_name_matches() {
+    record(Celsius);
+    let name = String::from("main");
+    panic_irrelevant!(name, "Only the main inventory is supported.", type_name == "alloc::string::String");
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Only the Celsius scale is supported. (the type is f64)")]
+fn type_name_differs() {
+    record(70.7);
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "(the type is &type_name::Celsius)")]
+fn type_name_of_reference() {
+    record(&Celsius);
+}
@@ -0,0 +1,18 @@
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn conditions_are_not_evaluated() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let output = Command::new(env!("CARGO"))
+        .args(["run", "--offline", "--quiet", "--manifest-path"])
+        .arg(manifest_dir.join("tests/unchecked_crate/Cargo.toml"))
+        .arg("--target-dir")
+        .arg(manifest_dir.join("target/unchecked"))
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(output.status.success(), "the unchecked crate failed to run: {stderr}");
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "0\n");
+    assert!(!stderr.contains("Assumption violated"), "{stderr}");
+}
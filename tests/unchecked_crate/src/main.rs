@@ -0,0 +1,41 @@
+//! A program ignoring values without the `checks` feature, to check the conditions are never evaluated. Run by
+//! `tests/unchecked.rs`, which expects it to print how many conditions were evaluated.
+#![deny(warnings)]
+use irrelevant::*;
+use std::cell::Cell;
+
+/// Counts how many times it is called, returning the new count. It is only called by the conditions, so it would be
+/// dead code if they weren't type-checked.
+fn evaluate(count: &Cell<u32>) -> u32 {
+    count.set(count.get() + 1);
+    count.get()
+}
+fn depth(levels: u32) {
+    recursion_guard!(0, "The recursion is never evaluated.");
+    if levels > 0 {
+        depth(levels - 1);
+    }
+}
+fn main() {
+    let count = Cell::new(0);
+    let sauces = ["ketchup"];
+    irrelevant!(sauces, "No sauces should come with a drink!", evaluate(&count) == 0);
+    let ice = 3;
+    panic_irrelevant!(ice, "Hot drinks come without ice.", evaluate(&count) == 0);
+    // Only used by the condition, which must not make it unused.
+    let max_cubes = 2;
+    let ice = 3;
+    irrelevant!(ice, "Drinks come with a few cubes at most.", ice <= max_cubes);
+    let straw = &();
+    irrelevant!(straw, "Straws are handed out at the counter.", &());
+    let cubes = vec![1, 2];
+    irrelevant!(cubes, "Hot drinks come without ice.", drained);
+    let port = 80;
+    irrelevant!(error: port, "Only unprivileged ports are used.", > 1023);
+    let latency = 1.0;
+    irrelevant!(latency, "Latency is only logged.", within_p99);
+    let (x, y) = (10, 10);
+    irrelevant!([x => evaluate(&count) == 0, y], "The cursor is hidden.");
+    depth(2);
+    println!("{}", count.get());
+}
@@ -0,0 +1,73 @@
+use irrelevant::*;
+use std::any::Any;
+#[cfg(feature = "checks")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+fn is_even(value: &dyn Any) -> bool {
+    value.downcast_ref::<u32>().is_some_and(|value| value % 2 == 0)
+}
+#[test]
+fn validator_passes() {
+    register_validator("even", is_even);
+    let pairs: u32 = 4;
+    panic_irrelevant!(pairs, "Items always come in pairs.", validator("even"));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Items always come in pairs. (rejected by the \"even\" validator)")]
+fn validator_fails() {
+    register_validator("even", is_even);
+    let pairs: u32 = 3;
+    panic_irrelevant!(pairs, "Items always come in pairs.", validator("even"));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "(rejected by the \"even\" validator)")]
+fn validator_wrong_type() {
+    register_validator("even", is_even);
+    let pairs: i64 = 4;
+    panic_irrelevant!(pairs, "Items always come in pairs.", validator("even"));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "(no \"odd\" validator is registered)")]
+fn validator_missing() {
+    let pairs: u32 = 3;
+    panic_irrelevant!(pairs, "Items never come in pairs.", validator("odd"));
+}
+#[cfg(feature = "checks")]
+static VALIDATED: AtomicUsize = AtomicUsize::new(0);
+#[cfg(feature = "checks")]
+fn is_identifier(value: &str) -> bool {
+    VALIDATED.fetch_add(1, Ordering::Relaxed);
+    value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+/// Ignores a column name, which is only used for logging.
+#[cfg(feature = "checks")]
+fn check_column(column: &str) {
+    panic_irrelevant!(column, "Columns are validated by the query planner.", cached_valid(is_identifier));
+}
+/// Ignores a table name, checked with the same validator as column names.
+#[cfg(feature = "checks")]
+fn check_table(table: &str) {
+    panic_irrelevant!(table, "Tables are validated by the query planner.", cached_valid(is_identifier));
+}
+// The validator counts its calls, so all the scenarios share a single test.
+#[cfg(feature = "checks")]
+#[test]
+fn cached_validator() {
+    for _ in 0..3 {
+        check_column("user_id");
+        check_column("created_at");
+        // The results are cached for the validator, not for the place using it.
+        check_table("user_id");
+    }
+    #[cfg(feature = "validation-cache")]
+    assert_eq!(VALIDATED.load(Ordering::Relaxed), 2);
+    #[cfg(not(feature = "validation-cache"))]
+    assert_eq!(VALIDATED.load(Ordering::Relaxed), 9);
+
+    let result = std::panic::catch_unwind(|| check_column("id; DROP TABLE users"));
+    let message = *result.unwrap_err().downcast::<String>().unwrap();
+    assert!(message.ends_with("(\"id; DROP TABLE users\" was rejected by the validator)"), "{message}");
+}
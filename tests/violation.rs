@@ -0,0 +1,57 @@
+use irrelevant::*;
+
+#[test]
+fn no_violation() {
+    let sauces: [&str; 0] = [];
+    irrelevant!(sauces, "No sauces should come with a drink!", is_empty);
+    assert_eq!(last_violation(), None);
+}
+#[cfg(feature = "checks")]
+#[test]
+fn last_violation_is_the_latest() {
+    let sauces = ["ketchup"];
+    irrelevant!(sauces, "No sauces should come with a drink!", is_empty);
+    let first = last_violation().unwrap();
+    assert_eq!(first.reason, "No sauces should come with a drink!");
+    assert_eq!(first.location.file, "tests/violation.rs");
+    assert_eq!(first.location.line, 13);
+
+    let ice = 3;
+    irrelevant!(ice, "Hot drinks come without ice.", <= 0);
+    let second = last_violation().unwrap();
+    assert_eq!(second.reason, "Hot drinks come without ice.");
+    assert_eq!(second.location.line, 20);
+    // Checks that hold don't affect the record.
+    let shots = 1;
+    irrelevant!(shots, "Every latte comes with espresso.", >= 1);
+    assert_eq!(last_violation(), Some(second));
+}
+#[cfg(feature = "checks")]
+#[test]
+fn panics_are_recorded() {
+    let result = std::panic::catch_unwind(|| {
+        let sauces = ["ketchup"];
+        panic_irrelevant!(sauces, "No sauces should come with a drink!", is_empty);
+    });
+    assert!(result.is_err());
+    assert_eq!(last_violation().unwrap().reason, "No sauces should come with a drink!");
+}
+#[test]
+fn violations_are_per_thread() {
+    std::thread::spawn(|| {
+        let sauces = ["ketchup"];
+        irrelevant!(sauces, "No sauces should come with a drink!", is_empty);
+    })
+    .join()
+    .unwrap();
+    assert_eq!(last_violation(), None);
+}
+#[cfg(feature = "checks")]
+#[test]
+fn records_own_their_reasons() {
+    let sauces = ["ketchup"];
+    irrelevant!(sauces, "No sauces should come with a drink!", is_empty);
+    let violation = last_violation().unwrap();
+    let reason: String = violation.reason;
+    assert_eq!(reason, "No sauces should come with a drink!");
+}
@@ -0,0 +1,36 @@
+use irrelevant::checks::Witnessed;
+use irrelevant::*;
+
+mod orders {
+    use irrelevant::checks::Witnessed;
+
+    pub struct Priced(());
+    pub struct Order {
+        pub total: u32,
+    }
+    pub fn price(items: &[u32]) -> Witnessed<Order, Priced> {
+        Witnessed::new(Order { total: items.iter().sum() }, Priced(()))
+    }
+    #[cfg(feature = "checks")]
+    pub fn draft() -> Witnessed<Order, Priced> {
+        Witnessed::unwitnessed(Order { total: 0 })
+    }
+}
+#[test]
+fn with_witness() {
+    let order = orders::price(&[3, 4]);
+    assert_eq!(order.value().total, 7);
+    panic_irrelevant!(order, "Orders are always priced before shipping.", witness(orders::Priced));
+}
+#[cfg(feature = "checks")]
+#[test]
+#[should_panic(expected = "Assumption violated: Orders are always priced before shipping. (the value was created without a witness::orders::Priced witness)")]
+fn without_witness() {
+    let order = orders::draft();
+    panic_irrelevant!(order, "Orders are always priced before shipping.", witness(orders::Priced));
+}
+#[test]
+fn into_inner() {
+    let order: Witnessed<_, ()> = Witnessed::unwitnessed(orders::Order { total: 5 });
+    assert_eq!(order.into_inner().total, 5);
+}